@@ -21,7 +21,14 @@ Register-ArgumentCompleter -Native -CommandName 'envio' -ScriptBlock {
 
     $completions = @(switch ($command) {
         'envio' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
             [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('init', 'init', [CompletionResultType]::ParameterValue, 'Initialize envio to be used in the current project directory')
@@ -29,13 +36,24 @@ Register-ArgumentCompleter -Native -CommandName 'envio' -ScriptBlock {
             [CompletionResult]::new('new', 'new', [CompletionResultType]::ParameterValue, 'Create a new profile')
             [CompletionResult]::new('delete', 'delete', [CompletionResultType]::ParameterValue, 'Delete a profile')
             [CompletionResult]::new('remove', 'remove', [CompletionResultType]::ParameterValue, 'Delete a profile')
+            [CompletionResult]::new('rename', 'rename', [CompletionResultType]::ParameterValue, 'Rename a profile')
+            [CompletionResult]::new('mv', 'mv', [CompletionResultType]::ParameterValue, 'Rename a profile')
+            [CompletionResult]::new('copy', 'copy', [CompletionResultType]::ParameterValue, 'Copy a profile under a new name and uuid')
+            [CompletionResult]::new('cp', 'cp', [CompletionResultType]::ParameterValue, 'Copy a profile under a new name and uuid')
             [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List all profiles')
             [CompletionResult]::new('ls', 'ls', [CompletionResultType]::ParameterValue, 'List all profiles')
             [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show environment variables in a profile')
-            [CompletionResult]::new('check', 'check', [CompletionResultType]::ParameterValue, 'Check for expired and upcoming environment variables in a profile')
+            [CompletionResult]::new('grep', 'grep', [CompletionResultType]::ParameterValue, 'Search every profile for a key, value or comment')
+            [CompletionResult]::new('scan', 'scan', [CompletionResultType]::ParameterValue, 'Search the project''s files for values of its profiles')
+            [CompletionResult]::new('lint', 'lint', [CompletionResultType]::ParameterValue, 'Check profiles for invalid names, leftover quotes, placeholders and similar problems')
+            [CompletionResult]::new('check', 'check', [CompletionResultType]::ParameterValue, 'Check a profile for expired variables and against the project''s schema')
             [CompletionResult]::new('edit', 'edit', [CompletionResultType]::ParameterValue, 'Edit a profile using your default text editor')
             [CompletionResult]::new('set', 'set', [CompletionResultType]::ParameterValue, 'Set or update environment variables in a profile')
             [CompletionResult]::new('unset', 'unset', [CompletionResultType]::ParameterValue, 'Remove environment variables from a profile')
+            [CompletionResult]::new('mv-var', 'mv-var', [CompletionResultType]::ParameterValue, 'Move environment variables from one profile to another')
+            [CompletionResult]::new('cp-var', 'cp-var', [CompletionResultType]::ParameterValue, 'Copy environment variables from one profile to another')
+            [CompletionResult]::new('rotate-var', 'rotate-var', [CompletionResultType]::ParameterValue, 'Replace the value of a variable, keeping the old one in the profile')
+            [CompletionResult]::new('use', 'use', [CompletionResultType]::ParameterValue, 'Set the default profile of the project, used when a command is not given one')
             [CompletionResult]::new('shell', 'shell', [CompletionResultType]::ParameterValue, 'Spawn a new shell with environment variables loaded from the profile')
             [CompletionResult]::new('run', 'run', [CompletionResultType]::ParameterValue, 'Run a command using environment variables from a profile')
             [CompletionResult]::new('import', 'import', [CompletionResultType]::ParameterValue, 'Import a profile from a file or url')
@@ -45,11 +63,26 @@ Register-ArgumentCompleter -Native -CommandName 'envio' -ScriptBlock {
             [CompletionResult]::new('rotate-key', 'rotate-key', [CompletionResultType]::ParameterValue, 'Rotate the encryption key of a profile')
             [CompletionResult]::new('tui', 'tui', [CompletionResultType]::ParameterValue, 'Launch the interactive TUI application')
             [CompletionResult]::new('completion', 'completion', [CompletionResultType]::ParameterValue, 'Show shell completion for the provided shell')
+            [CompletionResult]::new('hook', 'hook', [CompletionResultType]::ParameterValue, 'Print a shell hook that loads the active profile when entering a directory')
+            [CompletionResult]::new('hook-env', 'hook-env', [CompletionResultType]::ParameterValue, 'hook-env')
+            [CompletionResult]::new('allow', 'allow', [CompletionResultType]::ParameterValue, 'Allow the shell hook to load the active profile of a directory')
+            [CompletionResult]::new('config', 'config', [CompletionResultType]::ParameterValue, 'Get and set envio settings')
+            [CompletionResult]::new('migrate', 'migrate', [CompletionResultType]::ParameterValue, 'Move the profiles to another storage backend and switch to it')
+            [CompletionResult]::new('backup', 'backup', [CompletionResultType]::ParameterValue, 'Write every profile and the config to one encrypted archive')
+            [CompletionResult]::new('restore', 'restore', [CompletionResultType]::ParameterValue, 'Restore profiles, config and keys from a backup archive')
+            [CompletionResult]::new('schema', 'schema', [CompletionResultType]::ParameterValue, 'Manage the schema of the variables the project needs')
             [CompletionResult]::new('version', 'version', [CompletionResultType]::ParameterValue, 'Print version information')
             break
         }
         'envio;init' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
             [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
@@ -63,11 +96,22 @@ Register-ArgumentCompleter -Native -CommandName 'envio' -ScriptBlock {
             [CompletionResult]::new('--envs', '--envs', [CompletionResultType]::ParameterName, 'environment variables to add (format: KEY=VALUE or only provide KEY and the value will be prompted for)')
             [CompletionResult]::new('-k', '-k', [CompletionResultType]::ParameterName, 'encryption cipher to use')
             [CompletionResult]::new('--cipher-kind', '--cipher-kind', [CompletionResultType]::ParameterName, 'encryption cipher to use')
+            [CompletionResult]::new('-s', '-s', [CompletionResultType]::ParameterName, 'mark these variables as secret, their values are masked when displayed')
+            [CompletionResult]::new('--secret', '--secret', [CompletionResultType]::ParameterName, 'mark these variables as secret, their values are masked when displayed')
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
             [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'add comments to the provided environment variables')
             [CompletionResult]::new('--comments', '--comments', [CompletionResultType]::ParameterName, 'add comments to the provided environment variables')
             [CompletionResult]::new('-x', '-x', [CompletionResultType]::ParameterName, 'add expiration dates to the provided environment variables')
             [CompletionResult]::new('--expires', '--expires', [CompletionResultType]::ParameterName, 'add expiration dates to the provided environment variables')
+            [CompletionResult]::new('--no-comments', '--no-comments', [CompletionResultType]::ParameterName, 'don''t ask for comments, overriding create.comments from the config')
+            [CompletionResult]::new('--no-expires', '--no-expires', [CompletionResultType]::ParameterName, 'don''t ask for expiration dates, overriding create.expires from the config')
             [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
@@ -81,88 +125,394 @@ Register-ArgumentCompleter -Native -CommandName 'envio' -ScriptBlock {
             [CompletionResult]::new('--envs', '--envs', [CompletionResultType]::ParameterName, 'environment variables to add (format: KEY=VALUE or only provide KEY and the value will be prompted for)')
             [CompletionResult]::new('-k', '-k', [CompletionResultType]::ParameterName, 'encryption cipher to use')
             [CompletionResult]::new('--cipher-kind', '--cipher-kind', [CompletionResultType]::ParameterName, 'encryption cipher to use')
+            [CompletionResult]::new('-s', '-s', [CompletionResultType]::ParameterName, 'mark these variables as secret, their values are masked when displayed')
+            [CompletionResult]::new('--secret', '--secret', [CompletionResultType]::ParameterName, 'mark these variables as secret, their values are masked when displayed')
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
             [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'add comments to the provided environment variables')
             [CompletionResult]::new('--comments', '--comments', [CompletionResultType]::ParameterName, 'add comments to the provided environment variables')
             [CompletionResult]::new('-x', '-x', [CompletionResultType]::ParameterName, 'add expiration dates to the provided environment variables')
             [CompletionResult]::new('--expires', '--expires', [CompletionResultType]::ParameterName, 'add expiration dates to the provided environment variables')
+            [CompletionResult]::new('--no-comments', '--no-comments', [CompletionResultType]::ParameterName, 'don''t ask for comments, overriding create.comments from the config')
+            [CompletionResult]::new('--no-expires', '--no-expires', [CompletionResultType]::ParameterName, 'don''t ask for expiration dates, overriding create.expires from the config')
             [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
         }
         'envio;delete' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
             [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
         }
         'envio;remove' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
             [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'envio;rename' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'envio;mv' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'envio;copy' {
+            [CompletionResult]::new('-k', '-k', [CompletionResultType]::ParameterName, 're-encrypt the copy with this cipher')
+            [CompletionResult]::new('--cipher-kind', '--cipher-kind', [CompletionResultType]::ParameterName, 're-encrypt the copy with this cipher')
+            [CompletionResult]::new('-d', '-d', [CompletionResultType]::ParameterName, 'description of the copy (default: the one of the profile)')
+            [CompletionResult]::new('--description', '--description', [CompletionResultType]::ParameterName, 'description of the copy (default: the one of the profile)')
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('--new-key', '--new-key', [CompletionResultType]::ParameterName, 're-encrypt the copy under a new key with the same cipher')
+            [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'envio;cp' {
+            [CompletionResult]::new('-k', '-k', [CompletionResultType]::ParameterName, 're-encrypt the copy with this cipher')
+            [CompletionResult]::new('--cipher-kind', '--cipher-kind', [CompletionResultType]::ParameterName, 're-encrypt the copy with this cipher')
+            [CompletionResult]::new('-d', '-d', [CompletionResultType]::ParameterName, 'description of the copy (default: the one of the profile)')
+            [CompletionResult]::new('--description', '--description', [CompletionResultType]::ParameterName, 'description of the copy (default: the one of the profile)')
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('--new-key', '--new-key', [CompletionResultType]::ParameterName, 're-encrypt the copy under a new key with the same cipher')
+            [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
         }
         'envio;list' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
             [CompletionResult]::new('--no-pretty-print', '--no-pretty-print', [CompletionResultType]::ParameterName, 'disable pretty printing')
             [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
         }
         'envio;ls' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
             [CompletionResult]::new('--no-pretty-print', '--no-pretty-print', [CompletionResultType]::ParameterName, 'disable pretty printing')
             [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
         }
         'envio;show' {
+            [CompletionResult]::new('--tag', '--tag', [CompletionResultType]::ParameterName, 'comma-separated list of tags, only variables with one of them are shown')
+            [CompletionResult]::new('--reveal', '--reveal', [CompletionResultType]::ParameterName, 'display secret values, all of them or only the given keys (format: --reveal=KEY,...)')
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
             [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'display comments')
             [CompletionResult]::new('--show-comments', '--show-comments', [CompletionResultType]::ParameterName, 'display comments')
             [CompletionResult]::new('-x', '-x', [CompletionResultType]::ParameterName, 'display expiration dates')
             [CompletionResult]::new('--show-expiration', '--show-expiration', [CompletionResultType]::ParameterName, 'display expiration dates')
             [CompletionResult]::new('--no-pretty-print', '--no-pretty-print', [CompletionResultType]::ParameterName, 'disable pretty printing')
+            [CompletionResult]::new('-m', '-m', [CompletionResultType]::ParameterName, 'display tags, labels and when each variable was last updated')
+            [CompletionResult]::new('--show-metadata', '--show-metadata', [CompletionResultType]::ParameterName, 'display tags, labels and when each variable was last updated')
+            [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'envio;grep' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('--keys', '--keys', [CompletionResultType]::ParameterName, 'search the keys (default: keys, values and comments)')
+            [CompletionResult]::new('--values', '--values', [CompletionResultType]::ParameterName, 'search the values')
+            [CompletionResult]::new('--comments', '--comments', [CompletionResultType]::ParameterName, 'search the comments')
+            [CompletionResult]::new('-E', '-E ', [CompletionResultType]::ParameterName, 'treat the pattern as a regular expression')
+            [CompletionResult]::new('--regex', '--regex', [CompletionResultType]::ParameterName, 'treat the pattern as a regular expression')
+            [CompletionResult]::new('-i', '-i', [CompletionResultType]::ParameterName, 'match regardless of case')
+            [CompletionResult]::new('--ignore-case', '--ignore-case', [CompletionResultType]::ParameterName, 'match regardless of case')
             [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'envio;scan' {
+            [CompletionResult]::new('--min-length', '--min-length', [CompletionResultType]::ParameterName, 'ignore values shorter than this, at least 1')
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('--staged', '--staged', [CompletionResultType]::ParameterName, 'scan the content staged for the next commit, for a pre-commit hook')
+            [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'envio;lint' {
+            [CompletionResult]::new('--disable', '--disable', [CompletionResultType]::ParameterName, 'skip a rule, can be repeated')
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('--fix', '--fix', [CompletionResultType]::ParameterName, 'fix what can be fixed without asking and save the profiles')
+            [CompletionResult]::new('--strict', '--strict', [CompletionResultType]::ParameterName, 'fail on warnings too')
+            [CompletionResult]::new('--list-rules', '--list-rules', [CompletionResultType]::ParameterName, 'list the rules and exit')
+            [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
         }
         'envio;check' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('-a', '-a', [CompletionResultType]::ParameterName, 'check every profile')
+            [CompletionResult]::new('--all', '--all', [CompletionResultType]::ParameterName, 'check every profile')
             [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
         }
         'envio;edit' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
             [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
         }
         'envio;set' {
+            [CompletionResult]::new('-t', '-t', [CompletionResultType]::ParameterName, 'comma-separated list of tags replacing those of the provided environment variables')
+            [CompletionResult]::new('--tag', '--tag', [CompletionResultType]::ParameterName, 'comma-separated list of tags replacing those of the provided environment variables')
+            [CompletionResult]::new('-l', '-l', [CompletionResultType]::ParameterName, 'label to add to the provided environment variables, can be repeated (an empty value removes the label)')
+            [CompletionResult]::new('--label', '--label', [CompletionResultType]::ParameterName, 'label to add to the provided environment variables, can be repeated (an empty value removes the label)')
+            [CompletionResult]::new('--rotate-every', '--rotate-every', [CompletionResultType]::ParameterName, 'how often the provided environment variables should be rotated, e.g. 90d or 12w (off removes the interval)')
+            [CompletionResult]::new('--rotate-command', '--rotate-command', [CompletionResultType]::ParameterName, 'shell command printing a new value for `envio rotate-var`, it gets the old value on stdin (an empty command removes it)')
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
             [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'add comments to the provided environment variables')
             [CompletionResult]::new('--comments', '--comments', [CompletionResultType]::ParameterName, 'add comments to the provided environment variables')
             [CompletionResult]::new('-x', '-x', [CompletionResultType]::ParameterName, 'add expiration dates to the provided environment variables')
             [CompletionResult]::new('--expires', '--expires', [CompletionResultType]::ParameterName, 'add expiration dates to the provided environment variables')
+            [CompletionResult]::new('-s', '-s', [CompletionResultType]::ParameterName, 'mark the provided environment variables as secret, their values are masked when displayed')
+            [CompletionResult]::new('--secret', '--secret', [CompletionResultType]::ParameterName, 'mark the provided environment variables as secret, their values are masked when displayed')
+            [CompletionResult]::new('--plain', '--plain', [CompletionResultType]::ParameterName, 'mark the provided environment variables as plain, their values are displayed')
             [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
         }
         'envio;unset' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'envio;mv-var' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('-f', '-f', [CompletionResultType]::ParameterName, 'overwrite variables that already exist in <TO>')
+            [CompletionResult]::new('--force', '--force', [CompletionResultType]::ParameterName, 'overwrite variables that already exist in <TO>')
+            [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'envio;cp-var' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('-f', '-f', [CompletionResultType]::ParameterName, 'overwrite variables that already exist in <TO>')
+            [CompletionResult]::new('--force', '--force', [CompletionResultType]::ParameterName, 'overwrite variables that already exist in <TO>')
+            [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'envio;rotate-var' {
+            [CompletionResult]::new('-x', '-x', [CompletionResultType]::ParameterName, 'new expiration date (format: YYYY-MM-DD, default: today plus the rotation interval)')
+            [CompletionResult]::new('--expires', '--expires', [CompletionResultType]::ParameterName, 'new expiration date (format: YYYY-MM-DD, default: today plus the rotation interval)')
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('--rollback', '--rollback', [CompletionResultType]::ParameterName, 'restore the value replaced by the last rotation')
+            [CompletionResult]::new('--run-command', '--run-command', [CompletionResultType]::ParameterName, 'run the rotation command stored in the profile without asking, --no-input doesn''t imply it')
+            [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'envio;use' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('--clear', '--clear', [CompletionResultType]::ParameterName, 'remove the default profile')
             [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
         }
         'envio;shell' {
+            [CompletionResult]::new('-p', '-p', [CompletionResultType]::ParameterName, 'additional profile to load, can be repeated (later profiles override earlier ones)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'additional profile to load, can be repeated (later profiles override earlier ones)')
+            [CompletionResult]::new('--keep', '--keep', [CompletionResultType]::ParameterName, 'comma-separated list of extra variables to keep from the current environment with --clean')
+            [CompletionResult]::new('--only', '--only', [CompletionResultType]::ParameterName, 'comma-separated list of keys to load from the profile (''*'' matches any characters)')
+            [CompletionResult]::new('--except', '--except', [CompletionResultType]::ParameterName, 'comma-separated list of keys to skip from the profile (''*'' matches any characters)')
+            [CompletionResult]::new('--tag', '--tag', [CompletionResultType]::ParameterName, 'comma-separated list of tags, only variables with one of them are loaded from the profile')
+            [CompletionResult]::new('--prefix', '--prefix', [CompletionResultType]::ParameterName, 'prefix to add to every key loaded from the profile')
+            [CompletionResult]::new('--strip-prefix', '--strip-prefix', [CompletionResultType]::ParameterName, 'prefix to remove from keys loaded from the profile')
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('--clean', '--clean', [CompletionResultType]::ParameterName, 'start from an empty environment, only keeping essentials such as PATH, HOME and TERM')
             [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
         }
         'envio;run' {
+            [CompletionResult]::new('-p', '-p', [CompletionResultType]::ParameterName, 'additional profile to load, can be repeated (later profiles override earlier ones)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'additional profile to load, can be repeated (later profiles override earlier ones)')
+            [CompletionResult]::new('--keep', '--keep', [CompletionResultType]::ParameterName, 'comma-separated list of extra variables to keep from the current environment with --clean')
+            [CompletionResult]::new('--only', '--only', [CompletionResultType]::ParameterName, 'comma-separated list of keys to load from the profile (''*'' matches any characters)')
+            [CompletionResult]::new('--except', '--except', [CompletionResultType]::ParameterName, 'comma-separated list of keys to skip from the profile (''*'' matches any characters)')
+            [CompletionResult]::new('--tag', '--tag', [CompletionResultType]::ParameterName, 'comma-separated list of tags, only variables with one of them are loaded from the profile')
+            [CompletionResult]::new('--prefix', '--prefix', [CompletionResultType]::ParameterName, 'prefix to add to every key loaded from the profile')
+            [CompletionResult]::new('--strip-prefix', '--strip-prefix', [CompletionResultType]::ParameterName, 'prefix to remove from keys loaded from the profile')
+            [CompletionResult]::new('--restart-signal', '--restart-signal', [CompletionResultType]::ParameterName, 'signal sent to the command before restarting it')
+            [CompletionResult]::new('--grace-period', '--grace-period', [CompletionResultType]::ParameterName, 'seconds to wait for the command to exit before killing it')
+            [CompletionResult]::new('--on-change', '--on-change', [CompletionResultType]::ParameterName, 'run this shell command with the new environment instead of restarting')
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('--clean', '--clean', [CompletionResultType]::ParameterName, 'start from an empty environment, only keeping essentials such as PATH, HOME and TERM')
+            [CompletionResult]::new('--exec', '--exec', [CompletionResultType]::ParameterName, 'replace envio with the command instead of running it as a child process (unix only)')
+            [CompletionResult]::new('--redact', '--redact', [CompletionResultType]::ParameterName, 'replace profile values in the command''s output with ***')
+            [CompletionResult]::new('--no-redact', '--no-redact', [CompletionResultType]::ParameterName, 'don''t redact the command''s output, overriding run.redact from the config')
+            [CompletionResult]::new('--no-clean', '--no-clean', [CompletionResultType]::ParameterName, 'keep the current environment, overriding run.clean from the config')
+            [CompletionResult]::new('--redact-encoded', '--redact-encoded', [CompletionResultType]::ParameterName, 'also redact the base64 and URL-encoded forms of profile values, with --redact or run.redact')
+            [CompletionResult]::new('-w', '-w', [CompletionResultType]::ParameterName, 'restart the command when one of the profiles changes')
+            [CompletionResult]::new('--watch', '--watch', [CompletionResultType]::ParameterName, 'restart the command when one of the profiles changes')
             [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
@@ -170,7 +520,14 @@ Register-ArgumentCompleter -Native -CommandName 'envio' -ScriptBlock {
         'envio;import' {
             [CompletionResult]::new('-n', '-n', [CompletionResultType]::ParameterName, 'name for the imported profile')
             [CompletionResult]::new('--profile-name', '--profile-name', [CompletionResultType]::ParameterName, 'name for the imported profile')
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
             [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
@@ -180,47 +537,313 @@ Register-ArgumentCompleter -Native -CommandName 'envio' -ScriptBlock {
             [CompletionResult]::new('--output-file-path', '--output-file-path', [CompletionResultType]::ParameterName, 'output file path (default: .env)')
             [CompletionResult]::new('-k', '-k', [CompletionResultType]::ParameterName, 'comma-separated list of keys to export (type ''select'' to choose interactively)')
             [CompletionResult]::new('--keys', '--keys', [CompletionResultType]::ParameterName, 'comma-separated list of keys to export (type ''select'' to choose interactively)')
-            [CompletionResult]::new('-f', '-f', [CompletionResultType]::ParameterName, 'output format (dotenv, json, yaml, shell)')
-            [CompletionResult]::new('--format', '--format', [CompletionResultType]::ParameterName, 'output format (dotenv, json, yaml, shell)')
+            [CompletionResult]::new('-f', '-f', [CompletionResultType]::ParameterName, 'output format (dotenv, json, yaml, shell) (default: dotenv)')
+            [CompletionResult]::new('--format', '--format', [CompletionResultType]::ParameterName, 'output format (dotenv, json, yaml, shell) (default: dotenv)')
+            [CompletionResult]::new('--tag', '--tag', [CompletionResultType]::ParameterName, 'comma-separated list of tags, only variables with one of them are exported')
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
             [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
         }
         'envio;add-key' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
             [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
         }
         'envio;remove-key' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
             [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
         }
         'envio;rotate-key' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
             [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
         }
         'envio;tui' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
             [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
         }
         'envio;completion' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'envio;hook' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'envio;hook-env' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'envio;allow' {
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('--revoke', '--revoke', [CompletionResultType]::ParameterName, 'revoke a previous approval instead')
+            [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'envio;config' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('get', 'get', [CompletionResultType]::ParameterValue, 'Print the effective value of a setting')
+            [CompletionResult]::new('set', 'set', [CompletionResultType]::ParameterValue, 'Set a setting in the project config, or the user config with --global')
+            [CompletionResult]::new('unset', 'unset', [CompletionResultType]::ParameterValue, 'Remove a setting from the project config, or the user config with --global')
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List every setting with its value and where it comes from')
+            [CompletionResult]::new('ls', 'ls', [CompletionResultType]::ParameterValue, 'List every setting with its value and where it comes from')
+            break
+        }
+        'envio;config;get' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'envio;config;set' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'envio;config;unset' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'envio;config;list' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'envio;config;ls' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'envio;migrate' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'envio;backup' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'archive to write (default: envio-<date>.envio-backup)')
+            [CompletionResult]::new('--output-file-path', '--output-file-path', [CompletionResultType]::ParameterName, 'archive to write (default: envio-<date>.envio-backup)')
+            [CompletionResult]::new('-r', '-r', [CompletionResultType]::ParameterName, 'gpg key fingerprint to encrypt the archive for instead of a passphrase')
+            [CompletionResult]::new('--recipient', '--recipient', [CompletionResultType]::ParameterName, 'gpg key fingerprint to encrypt the archive for instead of a passphrase')
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('-i', '-i', [CompletionResultType]::ParameterName, 'also back up keyring keys, asks which profiles unless prompts are disabled')
+            [CompletionResult]::new('--include-keys', '--include-keys', [CompletionResultType]::ParameterName, 'also back up keyring keys, asks which profiles unless prompts are disabled')
+            [CompletionResult]::new('-f', '-f', [CompletionResultType]::ParameterName, 'overwrite an existing archive')
+            [CompletionResult]::new('--force', '--force', [CompletionResultType]::ParameterName, 'overwrite an existing archive')
+            [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'envio;restore' {
+            [CompletionResult]::new('--on-conflict', '--on-conflict', [CompletionResultType]::ParameterName, 'what to do with profiles that already exist (default: ask)')
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'only list what would be restored')
+            [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'envio;schema' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('generate', 'generate', [CompletionResultType]::ParameterValue, 'Write .envio/schema.toml listing the variables of a profile')
+            [CompletionResult]::new('example', 'example', [CompletionResultType]::ParameterValue, 'Write a .env.example with the keys and descriptions of the schema, without secrets')
+            break
+        }
+        'envio;schema;generate' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
+            [CompletionResult]::new('-f', '-f', [CompletionResultType]::ParameterName, 'overwrite an existing schema')
+            [CompletionResult]::new('--force', '--force', [CompletionResultType]::ParameterName, 'overwrite an existing schema')
+            [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'envio;schema;example' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'output file path (default: .env.example next to .envio)')
+            [CompletionResult]::new('--output-file-path', '--output-file-path', [CompletionResultType]::ParameterName, 'output file path (default: .env.example next to .envio)')
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
             [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
         }
         'envio;version' {
+            [CompletionResult]::new('--dir', '--dir', [CompletionResultType]::ParameterName, 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)')
             [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'show verbose version information')
             [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'show verbose version information')
             [CompletionResult]::new('--diagnostic', '--diagnostic', [CompletionResultType]::ParameterName, 'Show diagnostic information for bug reports')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'use the global profile store instead of the project''s')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
@@ -1,6 +1,6 @@
 # Print an optspec for argparse to handle cmd's options that are independent of any subcommand.
 function __fish_envio_global_optspecs
-	string join \n diagnostic h/help
+	string join \n diagnostic dir= g/global y/no-input output= h/help
 end
 
 function __fish_envio_needs_command
@@ -24,20 +24,38 @@ function __fish_envio_using_subcommand
 	contains -- $cmd[1] $argv
 end
 
+complete -c envio -n "__fish_envio_needs_command" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_needs_command" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
 complete -c envio -n "__fish_envio_needs_command" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_needs_command" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_needs_command" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
 complete -c envio -n "__fish_envio_needs_command" -s h -l help -d 'Print help'
 complete -c envio -n "__fish_envio_needs_command" -f -a "init" -d 'Initialize envio to be used in the current project directory'
 complete -c envio -n "__fish_envio_needs_command" -f -a "create" -d 'Create a new profile'
 complete -c envio -n "__fish_envio_needs_command" -f -a "new" -d 'Create a new profile'
 complete -c envio -n "__fish_envio_needs_command" -f -a "delete" -d 'Delete a profile'
 complete -c envio -n "__fish_envio_needs_command" -f -a "remove" -d 'Delete a profile'
+complete -c envio -n "__fish_envio_needs_command" -f -a "rename" -d 'Rename a profile'
+complete -c envio -n "__fish_envio_needs_command" -f -a "mv" -d 'Rename a profile'
+complete -c envio -n "__fish_envio_needs_command" -f -a "copy" -d 'Copy a profile under a new name and uuid'
+complete -c envio -n "__fish_envio_needs_command" -f -a "cp" -d 'Copy a profile under a new name and uuid'
 complete -c envio -n "__fish_envio_needs_command" -f -a "list" -d 'List all profiles'
 complete -c envio -n "__fish_envio_needs_command" -f -a "ls" -d 'List all profiles'
 complete -c envio -n "__fish_envio_needs_command" -f -a "show" -d 'Show environment variables in a profile'
-complete -c envio -n "__fish_envio_needs_command" -f -a "check" -d 'Check for expired and upcoming environment variables in a profile'
+complete -c envio -n "__fish_envio_needs_command" -f -a "grep" -d 'Search every profile for a key, value or comment'
+complete -c envio -n "__fish_envio_needs_command" -f -a "scan" -d 'Search the project\'s files for values of its profiles'
+complete -c envio -n "__fish_envio_needs_command" -f -a "lint" -d 'Check profiles for invalid names, leftover quotes, placeholders and similar problems'
+complete -c envio -n "__fish_envio_needs_command" -f -a "check" -d 'Check a profile for expired variables and against the project\'s schema'
 complete -c envio -n "__fish_envio_needs_command" -f -a "edit" -d 'Edit a profile using your default text editor'
 complete -c envio -n "__fish_envio_needs_command" -f -a "set" -d 'Set or update environment variables in a profile'
 complete -c envio -n "__fish_envio_needs_command" -f -a "unset" -d 'Remove environment variables from a profile'
+complete -c envio -n "__fish_envio_needs_command" -f -a "mv-var" -d 'Move environment variables from one profile to another'
+complete -c envio -n "__fish_envio_needs_command" -f -a "cp-var" -d 'Copy environment variables from one profile to another'
+complete -c envio -n "__fish_envio_needs_command" -f -a "rotate-var" -d 'Replace the value of a variable, keeping the old one in the profile'
+complete -c envio -n "__fish_envio_needs_command" -f -a "use" -d 'Set the default profile of the project, used when a command is not given one'
 complete -c envio -n "__fish_envio_needs_command" -f -a "shell" -d 'Spawn a new shell with environment variables loaded from the profile'
 complete -c envio -n "__fish_envio_needs_command" -f -a "run" -d 'Run a command using environment variables from a profile'
 complete -c envio -n "__fish_envio_needs_command" -f -a "import" -d 'Import a profile from a file or url'
@@ -47,75 +65,553 @@ complete -c envio -n "__fish_envio_needs_command" -f -a "remove-key" -d 'Remove
 complete -c envio -n "__fish_envio_needs_command" -f -a "rotate-key" -d 'Rotate the encryption key of a profile'
 complete -c envio -n "__fish_envio_needs_command" -f -a "tui" -d 'Launch the interactive TUI application'
 complete -c envio -n "__fish_envio_needs_command" -f -a "completion" -d 'Show shell completion for the provided shell'
+complete -c envio -n "__fish_envio_needs_command" -f -a "hook" -d 'Print a shell hook that loads the active profile when entering a directory'
+complete -c envio -n "__fish_envio_needs_command" -f -a "hook-env"
+complete -c envio -n "__fish_envio_needs_command" -f -a "allow" -d 'Allow the shell hook to load the active profile of a directory'
+complete -c envio -n "__fish_envio_needs_command" -f -a "config" -d 'Get and set envio settings'
+complete -c envio -n "__fish_envio_needs_command" -f -a "migrate" -d 'Move the profiles to another storage backend and switch to it'
+complete -c envio -n "__fish_envio_needs_command" -f -a "backup" -d 'Write every profile and the config to one encrypted archive'
+complete -c envio -n "__fish_envio_needs_command" -f -a "restore" -d 'Restore profiles, config and keys from a backup archive'
+complete -c envio -n "__fish_envio_needs_command" -f -a "schema" -d 'Manage the schema of the variables the project needs'
 complete -c envio -n "__fish_envio_needs_command" -f -a "version" -d 'Print version information'
+complete -c envio -n "__fish_envio_using_subcommand init" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand init" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
 complete -c envio -n "__fish_envio_using_subcommand init" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand init" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand init" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
 complete -c envio -n "__fish_envio_using_subcommand init" -s h -l help -d 'Print help'
 complete -c envio -n "__fish_envio_using_subcommand create" -s d -l description -d 'optional note or description of the profile' -r
 complete -c envio -n "__fish_envio_using_subcommand create" -s f -l from-file -d 'file path to load environment variables from' -r
 complete -c envio -n "__fish_envio_using_subcommand create" -s e -l envs -d 'environment variables to add (format: KEY=VALUE or only provide KEY and the value will be prompted for)' -r
 complete -c envio -n "__fish_envio_using_subcommand create" -s k -l cipher-kind -d 'encryption cipher to use' -r
+complete -c envio -n "__fish_envio_using_subcommand create" -s s -l secret -d 'mark these variables as secret, their values are masked when displayed' -r
+complete -c envio -n "__fish_envio_using_subcommand create" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand create" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
 complete -c envio -n "__fish_envio_using_subcommand create" -s c -l comments -d 'add comments to the provided environment variables'
 complete -c envio -n "__fish_envio_using_subcommand create" -s x -l expires -d 'add expiration dates to the provided environment variables'
+complete -c envio -n "__fish_envio_using_subcommand create" -l no-comments -d 'don\'t ask for comments, overriding create.comments from the config'
+complete -c envio -n "__fish_envio_using_subcommand create" -l no-expires -d 'don\'t ask for expiration dates, overriding create.expires from the config'
 complete -c envio -n "__fish_envio_using_subcommand create" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand create" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand create" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
 complete -c envio -n "__fish_envio_using_subcommand create" -s h -l help -d 'Print help'
 complete -c envio -n "__fish_envio_using_subcommand new" -s d -l description -d 'optional note or description of the profile' -r
 complete -c envio -n "__fish_envio_using_subcommand new" -s f -l from-file -d 'file path to load environment variables from' -r
 complete -c envio -n "__fish_envio_using_subcommand new" -s e -l envs -d 'environment variables to add (format: KEY=VALUE or only provide KEY and the value will be prompted for)' -r
 complete -c envio -n "__fish_envio_using_subcommand new" -s k -l cipher-kind -d 'encryption cipher to use' -r
+complete -c envio -n "__fish_envio_using_subcommand new" -s s -l secret -d 'mark these variables as secret, their values are masked when displayed' -r
+complete -c envio -n "__fish_envio_using_subcommand new" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand new" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
 complete -c envio -n "__fish_envio_using_subcommand new" -s c -l comments -d 'add comments to the provided environment variables'
 complete -c envio -n "__fish_envio_using_subcommand new" -s x -l expires -d 'add expiration dates to the provided environment variables'
+complete -c envio -n "__fish_envio_using_subcommand new" -l no-comments -d 'don\'t ask for comments, overriding create.comments from the config'
+complete -c envio -n "__fish_envio_using_subcommand new" -l no-expires -d 'don\'t ask for expiration dates, overriding create.expires from the config'
 complete -c envio -n "__fish_envio_using_subcommand new" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand new" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand new" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
 complete -c envio -n "__fish_envio_using_subcommand new" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand delete" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand delete" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
 complete -c envio -n "__fish_envio_using_subcommand delete" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand delete" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand delete" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
 complete -c envio -n "__fish_envio_using_subcommand delete" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand remove" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand remove" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
 complete -c envio -n "__fish_envio_using_subcommand remove" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand remove" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand remove" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
 complete -c envio -n "__fish_envio_using_subcommand remove" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand rename" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand rename" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand rename" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand rename" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand rename" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
+complete -c envio -n "__fish_envio_using_subcommand rename" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand mv" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand mv" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand mv" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand mv" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand mv" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
+complete -c envio -n "__fish_envio_using_subcommand mv" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand copy" -s k -l cipher-kind -d 're-encrypt the copy with this cipher' -r
+complete -c envio -n "__fish_envio_using_subcommand copy" -s d -l description -d 'description of the copy (default: the one of the profile)' -r
+complete -c envio -n "__fish_envio_using_subcommand copy" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand copy" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand copy" -l new-key -d 're-encrypt the copy under a new key with the same cipher'
+complete -c envio -n "__fish_envio_using_subcommand copy" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand copy" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand copy" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
+complete -c envio -n "__fish_envio_using_subcommand copy" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand cp" -s k -l cipher-kind -d 're-encrypt the copy with this cipher' -r
+complete -c envio -n "__fish_envio_using_subcommand cp" -s d -l description -d 'description of the copy (default: the one of the profile)' -r
+complete -c envio -n "__fish_envio_using_subcommand cp" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand cp" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand cp" -l new-key -d 're-encrypt the copy under a new key with the same cipher'
+complete -c envio -n "__fish_envio_using_subcommand cp" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand cp" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand cp" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
+complete -c envio -n "__fish_envio_using_subcommand cp" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand list" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand list" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
 complete -c envio -n "__fish_envio_using_subcommand list" -l no-pretty-print -d 'disable pretty printing'
 complete -c envio -n "__fish_envio_using_subcommand list" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand list" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand list" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
 complete -c envio -n "__fish_envio_using_subcommand list" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand ls" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand ls" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
 complete -c envio -n "__fish_envio_using_subcommand ls" -l no-pretty-print -d 'disable pretty printing'
 complete -c envio -n "__fish_envio_using_subcommand ls" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand ls" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand ls" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
 complete -c envio -n "__fish_envio_using_subcommand ls" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand show" -l tag -d 'comma-separated list of tags, only variables with one of them are shown' -r
+complete -c envio -n "__fish_envio_using_subcommand show" -l reveal -d 'display secret values, all of them or only the given keys (format: --reveal=KEY,...)' -r
+complete -c envio -n "__fish_envio_using_subcommand show" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand show" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
 complete -c envio -n "__fish_envio_using_subcommand show" -s c -l show-comments -d 'display comments'
 complete -c envio -n "__fish_envio_using_subcommand show" -s x -l show-expiration -d 'display expiration dates'
 complete -c envio -n "__fish_envio_using_subcommand show" -l no-pretty-print -d 'disable pretty printing'
+complete -c envio -n "__fish_envio_using_subcommand show" -s m -l show-metadata -d 'display tags, labels and when each variable was last updated'
 complete -c envio -n "__fish_envio_using_subcommand show" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand show" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand show" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
 complete -c envio -n "__fish_envio_using_subcommand show" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand grep" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand grep" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand grep" -l keys -d 'search the keys (default: keys, values and comments)'
+complete -c envio -n "__fish_envio_using_subcommand grep" -l values -d 'search the values'
+complete -c envio -n "__fish_envio_using_subcommand grep" -l comments -d 'search the comments'
+complete -c envio -n "__fish_envio_using_subcommand grep" -s E -l regex -d 'treat the pattern as a regular expression'
+complete -c envio -n "__fish_envio_using_subcommand grep" -s i -l ignore-case -d 'match regardless of case'
+complete -c envio -n "__fish_envio_using_subcommand grep" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand grep" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand grep" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
+complete -c envio -n "__fish_envio_using_subcommand grep" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand scan" -l min-length -d 'ignore values shorter than this, at least 1' -r
+complete -c envio -n "__fish_envio_using_subcommand scan" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand scan" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand scan" -l staged -d 'scan the content staged for the next commit, for a pre-commit hook'
+complete -c envio -n "__fish_envio_using_subcommand scan" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand scan" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand scan" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
+complete -c envio -n "__fish_envio_using_subcommand scan" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand lint" -l disable -d 'skip a rule, can be repeated' -r
+complete -c envio -n "__fish_envio_using_subcommand lint" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand lint" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand lint" -l fix -d 'fix what can be fixed without asking and save the profiles'
+complete -c envio -n "__fish_envio_using_subcommand lint" -l strict -d 'fail on warnings too'
+complete -c envio -n "__fish_envio_using_subcommand lint" -l list-rules -d 'list the rules and exit'
+complete -c envio -n "__fish_envio_using_subcommand lint" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand lint" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand lint" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
+complete -c envio -n "__fish_envio_using_subcommand lint" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand check" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand check" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand check" -s a -l all -d 'check every profile'
 complete -c envio -n "__fish_envio_using_subcommand check" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand check" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand check" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
 complete -c envio -n "__fish_envio_using_subcommand check" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand edit" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand edit" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
 complete -c envio -n "__fish_envio_using_subcommand edit" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand edit" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand edit" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
 complete -c envio -n "__fish_envio_using_subcommand edit" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand set" -s t -l tag -d 'comma-separated list of tags replacing those of the provided environment variables' -r
+complete -c envio -n "__fish_envio_using_subcommand set" -s l -l label -d 'label to add to the provided environment variables, can be repeated (an empty value removes the label)' -r
+complete -c envio -n "__fish_envio_using_subcommand set" -l rotate-every -d 'how often the provided environment variables should be rotated, e.g. 90d or 12w (off removes the interval)' -r
+complete -c envio -n "__fish_envio_using_subcommand set" -l rotate-command -d 'shell command printing a new value for `envio rotate-var`, it gets the old value on stdin (an empty command removes it)' -r
+complete -c envio -n "__fish_envio_using_subcommand set" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand set" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
 complete -c envio -n "__fish_envio_using_subcommand set" -s c -l comments -d 'add comments to the provided environment variables'
 complete -c envio -n "__fish_envio_using_subcommand set" -s x -l expires -d 'add expiration dates to the provided environment variables'
+complete -c envio -n "__fish_envio_using_subcommand set" -s s -l secret -d 'mark the provided environment variables as secret, their values are masked when displayed'
+complete -c envio -n "__fish_envio_using_subcommand set" -l plain -d 'mark the provided environment variables as plain, their values are displayed'
 complete -c envio -n "__fish_envio_using_subcommand set" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand set" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand set" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
 complete -c envio -n "__fish_envio_using_subcommand set" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand unset" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand unset" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
 complete -c envio -n "__fish_envio_using_subcommand unset" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand unset" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand unset" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
 complete -c envio -n "__fish_envio_using_subcommand unset" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand mv-var" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand mv-var" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand mv-var" -s f -l force -d 'overwrite variables that already exist in <TO>'
+complete -c envio -n "__fish_envio_using_subcommand mv-var" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand mv-var" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand mv-var" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
+complete -c envio -n "__fish_envio_using_subcommand mv-var" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand cp-var" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand cp-var" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand cp-var" -s f -l force -d 'overwrite variables that already exist in <TO>'
+complete -c envio -n "__fish_envio_using_subcommand cp-var" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand cp-var" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand cp-var" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
+complete -c envio -n "__fish_envio_using_subcommand cp-var" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand rotate-var" -s x -l expires -d 'new expiration date (format: YYYY-MM-DD, default: today plus the rotation interval)' -r
+complete -c envio -n "__fish_envio_using_subcommand rotate-var" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand rotate-var" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand rotate-var" -l rollback -d 'restore the value replaced by the last rotation'
+complete -c envio -n "__fish_envio_using_subcommand rotate-var" -l run-command -d 'run the rotation command stored in the profile without asking, --no-input doesn\'t imply it'
+complete -c envio -n "__fish_envio_using_subcommand rotate-var" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand rotate-var" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand rotate-var" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
+complete -c envio -n "__fish_envio_using_subcommand rotate-var" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand use" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand use" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand use" -l clear -d 'remove the default profile'
+complete -c envio -n "__fish_envio_using_subcommand use" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand use" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand use" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
+complete -c envio -n "__fish_envio_using_subcommand use" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand shell" -s p -l profile -d 'additional profile to load, can be repeated (later profiles override earlier ones)' -r
+complete -c envio -n "__fish_envio_using_subcommand shell" -l keep -d 'comma-separated list of extra variables to keep from the current environment with --clean' -r
+complete -c envio -n "__fish_envio_using_subcommand shell" -l only -d 'comma-separated list of keys to load from the profile (\'*\' matches any characters)' -r
+complete -c envio -n "__fish_envio_using_subcommand shell" -l except -d 'comma-separated list of keys to skip from the profile (\'*\' matches any characters)' -r
+complete -c envio -n "__fish_envio_using_subcommand shell" -l tag -d 'comma-separated list of tags, only variables with one of them are loaded from the profile' -r
+complete -c envio -n "__fish_envio_using_subcommand shell" -l prefix -d 'prefix to add to every key loaded from the profile' -r
+complete -c envio -n "__fish_envio_using_subcommand shell" -l strip-prefix -d 'prefix to remove from keys loaded from the profile' -r
+complete -c envio -n "__fish_envio_using_subcommand shell" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand shell" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand shell" -l clean -d 'start from an empty environment, only keeping essentials such as PATH, HOME and TERM'
 complete -c envio -n "__fish_envio_using_subcommand shell" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand shell" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand shell" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
 complete -c envio -n "__fish_envio_using_subcommand shell" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand run" -s p -l profile -d 'additional profile to load, can be repeated (later profiles override earlier ones)' -r
+complete -c envio -n "__fish_envio_using_subcommand run" -l keep -d 'comma-separated list of extra variables to keep from the current environment with --clean' -r
+complete -c envio -n "__fish_envio_using_subcommand run" -l only -d 'comma-separated list of keys to load from the profile (\'*\' matches any characters)' -r
+complete -c envio -n "__fish_envio_using_subcommand run" -l except -d 'comma-separated list of keys to skip from the profile (\'*\' matches any characters)' -r
+complete -c envio -n "__fish_envio_using_subcommand run" -l tag -d 'comma-separated list of tags, only variables with one of them are loaded from the profile' -r
+complete -c envio -n "__fish_envio_using_subcommand run" -l prefix -d 'prefix to add to every key loaded from the profile' -r
+complete -c envio -n "__fish_envio_using_subcommand run" -l strip-prefix -d 'prefix to remove from keys loaded from the profile' -r
+complete -c envio -n "__fish_envio_using_subcommand run" -l restart-signal -d 'signal sent to the command before restarting it' -r
+complete -c envio -n "__fish_envio_using_subcommand run" -l grace-period -d 'seconds to wait for the command to exit before killing it' -r
+complete -c envio -n "__fish_envio_using_subcommand run" -l on-change -d 'run this shell command with the new environment instead of restarting' -r
+complete -c envio -n "__fish_envio_using_subcommand run" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand run" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand run" -l clean -d 'start from an empty environment, only keeping essentials such as PATH, HOME and TERM'
+complete -c envio -n "__fish_envio_using_subcommand run" -l exec -d 'replace envio with the command instead of running it as a child process (unix only)'
+complete -c envio -n "__fish_envio_using_subcommand run" -l redact -d 'replace profile values in the command\'s output with ***'
+complete -c envio -n "__fish_envio_using_subcommand run" -l no-redact -d 'don\'t redact the command\'s output, overriding run.redact from the config'
+complete -c envio -n "__fish_envio_using_subcommand run" -l no-clean -d 'keep the current environment, overriding run.clean from the config'
+complete -c envio -n "__fish_envio_using_subcommand run" -l redact-encoded -d 'also redact the base64 and URL-encoded forms of profile values, with --redact or run.redact'
+complete -c envio -n "__fish_envio_using_subcommand run" -s w -l watch -d 'restart the command when one of the profiles changes'
 complete -c envio -n "__fish_envio_using_subcommand run" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand run" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand run" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
 complete -c envio -n "__fish_envio_using_subcommand run" -s h -l help -d 'Print help'
 complete -c envio -n "__fish_envio_using_subcommand import" -s n -l profile-name -d 'name for the imported profile' -r
+complete -c envio -n "__fish_envio_using_subcommand import" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand import" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
 complete -c envio -n "__fish_envio_using_subcommand import" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand import" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand import" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
 complete -c envio -n "__fish_envio_using_subcommand import" -s h -l help -d 'Print help'
 complete -c envio -n "__fish_envio_using_subcommand export" -s o -l output-file-path -d 'output file path (default: .env)' -r
 complete -c envio -n "__fish_envio_using_subcommand export" -s k -l keys -d 'comma-separated list of keys to export (type \'select\' to choose interactively)' -r
-complete -c envio -n "__fish_envio_using_subcommand export" -s f -l format -d 'output format (dotenv, json, yaml, shell)' -r -f -a "dotenv\t''
+complete -c envio -n "__fish_envio_using_subcommand export" -s f -l format -d 'output format (dotenv, json, yaml, shell) (default: dotenv)' -r -f -a "dotenv\t''
 json\t''
 yaml\t''
 shell\t''"
+complete -c envio -n "__fish_envio_using_subcommand export" -l tag -d 'comma-separated list of tags, only variables with one of them are exported' -r
+complete -c envio -n "__fish_envio_using_subcommand export" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand export" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
 complete -c envio -n "__fish_envio_using_subcommand export" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand export" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand export" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
 complete -c envio -n "__fish_envio_using_subcommand export" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand add-key" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand add-key" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
 complete -c envio -n "__fish_envio_using_subcommand add-key" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand add-key" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand add-key" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
 complete -c envio -n "__fish_envio_using_subcommand add-key" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand remove-key" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand remove-key" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
 complete -c envio -n "__fish_envio_using_subcommand remove-key" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand remove-key" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand remove-key" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
 complete -c envio -n "__fish_envio_using_subcommand remove-key" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand rotate-key" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand rotate-key" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
 complete -c envio -n "__fish_envio_using_subcommand rotate-key" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand rotate-key" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand rotate-key" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
 complete -c envio -n "__fish_envio_using_subcommand rotate-key" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand tui" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand tui" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
 complete -c envio -n "__fish_envio_using_subcommand tui" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand tui" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand tui" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
 complete -c envio -n "__fish_envio_using_subcommand tui" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand completion" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand completion" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
 complete -c envio -n "__fish_envio_using_subcommand completion" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand completion" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand completion" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
 complete -c envio -n "__fish_envio_using_subcommand completion" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand hook" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand hook" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand hook" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand hook" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand hook" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
+complete -c envio -n "__fish_envio_using_subcommand hook" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand hook-env" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand hook-env" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand hook-env" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand hook-env" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand hook-env" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
+complete -c envio -n "__fish_envio_using_subcommand hook-env" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand allow" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand allow" -l revoke -d 'revoke a previous approval instead'
+complete -c envio -n "__fish_envio_using_subcommand allow" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand allow" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand allow" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
+complete -c envio -n "__fish_envio_using_subcommand allow" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand config; and not __fish_seen_subcommand_from get set unset list ls" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand config; and not __fish_seen_subcommand_from get set unset list ls" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand config; and not __fish_seen_subcommand_from get set unset list ls" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand config; and not __fish_seen_subcommand_from get set unset list ls" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand config; and not __fish_seen_subcommand_from get set unset list ls" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
+complete -c envio -n "__fish_envio_using_subcommand config; and not __fish_seen_subcommand_from get set unset list ls" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand config; and not __fish_seen_subcommand_from get set unset list ls" -f -a "get" -d 'Print the effective value of a setting'
+complete -c envio -n "__fish_envio_using_subcommand config; and not __fish_seen_subcommand_from get set unset list ls" -f -a "set" -d 'Set a setting in the project config, or the user config with --global'
+complete -c envio -n "__fish_envio_using_subcommand config; and not __fish_seen_subcommand_from get set unset list ls" -f -a "unset" -d 'Remove a setting from the project config, or the user config with --global'
+complete -c envio -n "__fish_envio_using_subcommand config; and not __fish_seen_subcommand_from get set unset list ls" -f -a "list" -d 'List every setting with its value and where it comes from'
+complete -c envio -n "__fish_envio_using_subcommand config; and not __fish_seen_subcommand_from get set unset list ls" -f -a "ls" -d 'List every setting with its value and where it comes from'
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from get" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from get" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from get" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from get" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from get" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from get" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from set" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from set" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from set" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from set" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from set" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from set" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from unset" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from unset" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from unset" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from unset" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from unset" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from unset" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from list" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from list" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from list" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from list" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from list" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from list" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from ls" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from ls" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from ls" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from ls" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from ls" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
+complete -c envio -n "__fish_envio_using_subcommand config; and __fish_seen_subcommand_from ls" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand migrate" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand migrate" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand migrate" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand migrate" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand migrate" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
+complete -c envio -n "__fish_envio_using_subcommand migrate" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand backup" -s o -l output-file-path -d 'archive to write (default: envio-<date>.envio-backup)' -r
+complete -c envio -n "__fish_envio_using_subcommand backup" -s r -l recipient -d 'gpg key fingerprint to encrypt the archive for instead of a passphrase' -r
+complete -c envio -n "__fish_envio_using_subcommand backup" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand backup" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand backup" -s i -l include-keys -d 'also back up keyring keys, asks which profiles unless prompts are disabled'
+complete -c envio -n "__fish_envio_using_subcommand backup" -s f -l force -d 'overwrite an existing archive'
+complete -c envio -n "__fish_envio_using_subcommand backup" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand backup" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand backup" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
+complete -c envio -n "__fish_envio_using_subcommand backup" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand restore" -l on-conflict -d 'what to do with profiles that already exist (default: ask)' -r -f -a "skip\t''
+overwrite\t''
+rename\t''"
+complete -c envio -n "__fish_envio_using_subcommand restore" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand restore" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand restore" -l dry-run -d 'only list what would be restored'
+complete -c envio -n "__fish_envio_using_subcommand restore" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand restore" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand restore" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
+complete -c envio -n "__fish_envio_using_subcommand restore" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand schema; and not __fish_seen_subcommand_from generate example" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand schema; and not __fish_seen_subcommand_from generate example" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand schema; and not __fish_seen_subcommand_from generate example" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand schema; and not __fish_seen_subcommand_from generate example" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand schema; and not __fish_seen_subcommand_from generate example" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
+complete -c envio -n "__fish_envio_using_subcommand schema; and not __fish_seen_subcommand_from generate example" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand schema; and not __fish_seen_subcommand_from generate example" -f -a "generate" -d 'Write .envio/schema.toml listing the variables of a profile'
+complete -c envio -n "__fish_envio_using_subcommand schema; and not __fish_seen_subcommand_from generate example" -f -a "example" -d 'Write a .env.example with the keys and descriptions of the schema, without secrets'
+complete -c envio -n "__fish_envio_using_subcommand schema; and __fish_seen_subcommand_from generate" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand schema; and __fish_seen_subcommand_from generate" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand schema; and __fish_seen_subcommand_from generate" -s f -l force -d 'overwrite an existing schema'
+complete -c envio -n "__fish_envio_using_subcommand schema; and __fish_seen_subcommand_from generate" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand schema; and __fish_seen_subcommand_from generate" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand schema; and __fish_seen_subcommand_from generate" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
+complete -c envio -n "__fish_envio_using_subcommand schema; and __fish_seen_subcommand_from generate" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand schema; and __fish_seen_subcommand_from example" -s o -l output-file-path -d 'output file path (default: .env.example next to .envio)' -r
+complete -c envio -n "__fish_envio_using_subcommand schema; and __fish_seen_subcommand_from example" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand schema; and __fish_seen_subcommand_from example" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
+complete -c envio -n "__fish_envio_using_subcommand schema; and __fish_seen_subcommand_from example" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand schema; and __fish_seen_subcommand_from example" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand schema; and __fish_seen_subcommand_from example" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
+complete -c envio -n "__fish_envio_using_subcommand schema; and __fish_seen_subcommand_from example" -s h -l help -d 'Print help'
+complete -c envio -n "__fish_envio_using_subcommand version" -l dir -d 'path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)' -r
+complete -c envio -n "__fish_envio_using_subcommand version" -l output -d 'output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)' -r -f -a "table\t''
+plain\t''
+json\t''
+yaml\t''"
 complete -c envio -n "__fish_envio_using_subcommand version" -s v -l verbose -d 'show verbose version information'
 complete -c envio -n "__fish_envio_using_subcommand version" -l diagnostic -d 'Show diagnostic information for bug reports'
+complete -c envio -n "__fish_envio_using_subcommand version" -s g -l global -d 'use the global profile store instead of the project\'s'
+complete -c envio -n "__fish_envio_using_subcommand version" -s y -l no-input -l yes -d 'never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)'
 complete -c envio -n "__fish_envio_using_subcommand version" -s h -l help -d 'Print help'
@@ -0,0 +1,148 @@
+//! Encrypted single file backups of a whole profile store.
+//!
+//! A [`Backup`] holds the serialized profiles as they are on disk, so profiles
+//! stay encrypted under their own keys inside it, next to the config files of
+//! the `.envio` folder and the keyring keys the user chose to include. The
+//! whole backup is then encrypted once more with its own cipher, see
+//! [`Backup::seal`] and [`Backup::open`].
+
+use chrono::{DateTime, Local};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::{
+    cipher::{self, Cipher, CipherKind, EncryptedContent, PASSPHRASE},
+    env::EnvMap,
+    error::{Error, Result},
+    profile::SerializedProfile,
+};
+
+/// `format` field of every archive, tells backups apart from profile files
+pub const FORMAT: &str = "envio-backup";
+
+const VERSION: &str = "1";
+
+/// the single variable the backup is encrypted as, ciphers only take an [`EnvMap`]
+const PAYLOAD_KEY: &str = "ENVIO_BACKUP";
+
+#[derive(Serialize, Deserialize)]
+pub struct Backup {
+    pub created_at: DateTime<Local>,
+    /// version of envio that wrote the backup
+    pub envio_version: String,
+    pub profiles: Vec<SerializedProfile>,
+    /// files of the `.envio` folder besides the profiles by name, as in `config.toml`
+    #[serde(default)]
+    pub files: IndexMap<String, String>,
+    /// keyring keys by the uuid of their profile
+    #[serde(default)]
+    pub keys: IndexMap<String, Zeroizing<String>>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Archive {
+    format: String,
+    version: String,
+    cipher_kind: CipherKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cipher_metadata: Option<serde_json::Value>,
+    content: EncryptedContent,
+}
+
+impl Default for Backup {
+    fn default() -> Self {
+        Self {
+            created_at: Local::now(),
+            envio_version: env!("CARGO_PKG_VERSION").to_string(),
+            profiles: Vec::new(),
+            files: IndexMap::new(),
+            keys: IndexMap::new(),
+        }
+    }
+}
+
+impl Backup {
+    /// the archive bytes, encrypted with `cipher` which must not be `none`
+    pub fn seal(&self, mut cipher: Box<dyn Cipher>) -> Result<Vec<u8>> {
+        if cipher.kind() == CipherKind::NONE {
+            return Err(Error::Cipher("backups have to be encrypted".to_string()));
+        }
+
+        // the payload holds the keyring keys in plain text
+        let payload = Zeroizing::new(serde_json::to_string(self)?);
+        let mut envs = EnvMap::default();
+        envs.insert_from_key_value(PAYLOAD_KEY, payload.as_str());
+
+        let content = cipher.encrypt(&envs);
+        if let Some(env) = envs.get_mut(PAYLOAD_KEY) {
+            env.value.zeroize();
+        }
+        let content = content?;
+
+        Ok(serde_json::to_vec_pretty(&Archive {
+            format: FORMAT.to_string(),
+            version: VERSION.to_string(),
+            cipher_kind: cipher.kind(),
+            cipher_metadata: cipher.export_metadata(),
+            content,
+        })?)
+    }
+
+    /// the cipher an archive was sealed with, to know whether a key has to be asked for
+    pub fn cipher_kind(archive: &[u8]) -> Result<CipherKind> {
+        Ok(parse_archive(archive)?.cipher_kind)
+    }
+
+    /// decrypts an archive written by [`Backup::seal`], `key` is the backup
+    /// passphrase and unused for gpg
+    pub fn open(archive: &[u8], key: Option<Zeroizing<String>>) -> Result<Self> {
+        let archive = parse_archive(archive)?;
+
+        let mut cipher = cipher::create_cipher(archive.cipher_kind, None)?;
+
+        if let Some(cipher_metadata) = archive.cipher_metadata {
+            cipher.import_metadata(cipher_metadata)?;
+        }
+
+        if let Some(passphrase) = cipher.as_any_mut().downcast_mut::<PASSPHRASE>() {
+            let key =
+                key.ok_or_else(|| Error::Msg("the backup passphrase is required".to_string()))?;
+            passphrase.set_key(key);
+        }
+
+        let mut envs = cipher.decrypt(&archive.content).map_err(|e| match e {
+            Error::WrongKey => {
+                Error::Cipher("wrong passphrase, the backup could not be decrypted".to_string())
+            }
+            e => e,
+        })?;
+        let payload = envs
+            .get_mut(PAYLOAD_KEY)
+            .map(|env| Zeroizing::new(std::mem::take(&mut env.value)))
+            .ok_or_else(|| Error::Corrupted("backup has no content".to_string()))?;
+
+        serde_json::from_str(&payload).map_err(|e| Error::Corrupted(e.to_string()))
+    }
+}
+
+fn parse_archive(archive: &[u8]) -> Result<Archive> {
+    let archive: Archive = serde_json::from_slice(archive)
+        .map_err(|e| Error::Corrupted(format!("not an envio backup: {}", e)))?;
+
+    if archive.format != FORMAT {
+        return Err(Error::Corrupted(format!(
+            "not an envio backup, format is `{}`",
+            archive.format
+        )));
+    }
+
+    if archive.version != VERSION {
+        return Err(Error::UnsupportedVersion {
+            kind: "backup".to_string(),
+            version: archive.version,
+        });
+    }
+
+    Ok(archive)
+}
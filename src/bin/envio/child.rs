@@ -0,0 +1,258 @@
+use std::{
+    process::{Child, Command, ExitStatus},
+    thread::JoinHandle,
+    time::{Duration, Instant},
+};
+
+use crate::error::{AppError, AppResult};
+
+/// exit code a shell would report for `status`, `128 + signal` if the child was killed by a signal
+pub fn exit_code(status: ExitStatus) -> i32 {
+    #[cfg(target_family = "unix")]
+    {
+        use std::os::unix::process::ExitStatusExt;
+
+        if let Some(signal) = status.signal() {
+            return 128 + signal;
+        }
+    }
+
+    status.code().unwrap_or(1)
+}
+
+/// spawns `command` and waits for it, forwarding termination signals to the child
+pub fn spawn_and_wait(command: &mut Command) -> AppResult<i32> {
+    spawn_and_wait_with(command, |_| Vec::new())
+}
+
+/// like [`spawn_and_wait`], `on_spawn` can take the child's pipes and return the
+/// threads handling them, which are joined once the child has exited
+pub fn spawn_and_wait_with<F>(command: &mut Command, on_spawn: F) -> AppResult<i32>
+where
+    F: FnOnce(&mut Child) -> Vec<JoinHandle<()>>,
+{
+    #[cfg(target_family = "unix")]
+    let forwarder = unix::SignalForwarder::install()?;
+
+    let mut child = command
+        .spawn()
+        .map_err(|e| AppError::Msg(format!("Failed to spawn command: {}", e)))?;
+
+    let handles = on_spawn(&mut child);
+
+    #[cfg(target_family = "unix")]
+    forwarder.forward_to(child.id());
+
+    let code = wait(child);
+
+    #[cfg(target_family = "unix")]
+    forwarder.close();
+
+    for handle in handles {
+        let _ = handle.join();
+    }
+
+    code
+}
+
+#[cfg(target_family = "unix")]
+fn wait(child: Child) -> AppResult<i32> {
+    // as PID 1 we inherit every orphaned process in the container, so reap them
+    // all and only stop once our own child has exited
+    if std::process::id() == 1 {
+        return unix::reap_until(child.id());
+    }
+
+    wait_child(child)
+}
+
+#[cfg(not(target_family = "unix"))]
+fn wait(child: Child) -> AppResult<i32> {
+    wait_child(child)
+}
+
+fn wait_child(mut child: Child) -> AppResult<i32> {
+    let status = child
+        .wait()
+        .map_err(|e| AppError::Msg(format!("Failed to wait on command: {}", e)))?;
+
+    Ok(exit_code(status))
+}
+
+/// asks `child` to exit with `signal` and kills it if it is still running after `grace_period`
+#[cfg_attr(not(target_family = "unix"), allow(unused_variables))]
+pub fn stop(child: &mut Child, signal: i32, grace_period: Duration) -> AppResult<ExitStatus> {
+    #[cfg(target_family = "unix")]
+    if unix::send(child.id(), signal) {
+        let deadline = Instant::now() + grace_period;
+
+        while Instant::now() < deadline {
+            if let Some(status) = child.try_wait()? {
+                return Ok(status);
+            }
+            std::thread::sleep(Duration::from_millis(50));
+        }
+    }
+
+    let _ = child.kill();
+    Ok(child.wait()?)
+}
+
+/// parses a signal given by name (`TERM`, `SIGTERM`) or number
+#[cfg(target_family = "unix")]
+pub fn parse_signal(signal: &str) -> AppResult<i32> {
+    unix::parse_signal(signal)
+}
+
+/// signals only exist on unix, elsewhere the child is always killed
+#[cfg(not(target_family = "unix"))]
+pub fn parse_signal(_signal: &str) -> AppResult<i32> {
+    Ok(0)
+}
+
+/// replaces the current process with `command`, only returns if that fails
+#[cfg(target_family = "unix")]
+pub fn exec(command: &mut Command) -> AppError {
+    use std::os::unix::process::CommandExt;
+
+    AppError::Msg(format!("Failed to exec command: {}", command.exec()))
+}
+
+#[cfg(target_family = "unix")]
+pub mod unix {
+    use std::{
+        sync::{Arc, Mutex},
+        thread::JoinHandle,
+    };
+
+    use signal_hook::{
+        consts::{SIGHUP, SIGINT, SIGKILL, SIGQUIT, SIGTERM, SIGUSR1, SIGUSR2},
+        iterator::{Handle, Signals},
+    };
+
+    use super::exit_code;
+    use crate::error::{AppError, AppResult};
+
+    pub const FORWARDED_SIGNALS: &[i32] = &[SIGHUP, SIGINT, SIGQUIT, SIGTERM, SIGUSR1, SIGUSR2];
+
+    #[derive(Default)]
+    struct Target {
+        pid: Option<u32>,
+        pending: Vec<i32>,
+    }
+
+    /// Catches [`FORWARDED_SIGNALS`] and relays them to the current child.
+    ///
+    /// It is installed before the child is spawned so a signal arriving in between
+    /// is not lost, it is delivered as soon as the child pid is known.
+    pub struct SignalForwarder {
+        target: Arc<Mutex<Target>>,
+        handle: Handle,
+        thread: JoinHandle<()>,
+    }
+
+    impl SignalForwarder {
+        pub fn install() -> AppResult<Self> {
+            let mut signals = Signals::new(FORWARDED_SIGNALS)?;
+            let handle = signals.handle();
+            let target = Arc::new(Mutex::new(Target::default()));
+
+            let thread = {
+                let target = target.clone();
+                let from_terminal = in_foreground_of_terminal();
+
+                std::thread::spawn(move || {
+                    for signal in signals.forever() {
+                        // the terminal already sends these to the whole foreground
+                        // process group, which the child is part of
+                        if from_terminal && matches!(signal, SIGINT | SIGQUIT) {
+                            continue;
+                        }
+
+                        let mut target = target.lock().unwrap_or_else(|e| e.into_inner());
+                        match target.pid {
+                            Some(pid) => {
+                                send(pid, signal);
+                            }
+                            None => target.pending.push(signal),
+                        }
+                    }
+                })
+            };
+
+            Ok(Self {
+                target,
+                handle,
+                thread,
+            })
+        }
+
+        /// relays signals to `pid` from now on, including those caught before it was known
+        pub fn forward_to(&self, pid: u32) {
+            let mut target = self.target.lock().unwrap_or_else(|e| e.into_inner());
+            target.pid = Some(pid);
+
+            for signal in std::mem::take(&mut target.pending) {
+                send(pid, signal);
+            }
+        }
+
+        pub fn close(self) {
+            self.handle.close();
+            let _ = self.thread.join();
+        }
+    }
+
+    pub fn parse_signal(signal: &str) -> AppResult<i32> {
+        if let Ok(number) = signal.parse::<i32>() {
+            return Ok(number);
+        }
+
+        let upper = signal.to_ascii_uppercase();
+        let name = upper.strip_prefix("SIG").unwrap_or(&upper);
+
+        Ok(match name {
+            "HUP" => SIGHUP,
+            "INT" => SIGINT,
+            "QUIT" => SIGQUIT,
+            "KILL" => SIGKILL,
+            "USR1" => SIGUSR1,
+            "USR2" => SIGUSR2,
+            "TERM" => SIGTERM,
+            _ => return Err(AppError::Msg(format!("Unknown signal `{}`", signal))),
+        })
+    }
+
+    pub fn send(pid: u32, signal: i32) -> bool {
+        unsafe { libc::kill(pid as libc::pid_t, signal) == 0 }
+    }
+
+    fn in_foreground_of_terminal() -> bool {
+        unsafe {
+            libc::isatty(libc::STDIN_FILENO) == 1
+                && libc::tcgetpgrp(libc::STDIN_FILENO) == libc::getpgrp()
+        }
+    }
+
+    /// waits for any child until `pid` exits and returns its exit code
+    pub fn reap_until(pid: u32) -> AppResult<i32> {
+        loop {
+            let mut status = 0;
+            let reaped = unsafe { libc::waitpid(-1, &mut status, 0) };
+
+            if reaped < 0 {
+                let err = std::io::Error::last_os_error();
+                if err.kind() == std::io::ErrorKind::Interrupted {
+                    continue;
+                }
+
+                return Err(AppError::Msg(format!("Failed to wait on command: {}", err)));
+            }
+
+            if reaped as u32 == pid {
+                use std::os::unix::process::ExitStatusExt;
+                return Ok(exit_code(std::process::ExitStatus::from_raw(status)));
+            }
+        }
+    }
+}
@@ -11,6 +11,34 @@ pub struct ClapApp {
         global = true
     )]
     pub diagnostic: bool,
+    #[arg(
+        long = "dir",
+        help = "path to the .envio folder to use instead of searching for one (env: ENVIO_DIR)",
+        global = true
+    )]
+    pub dir: Option<String>,
+    #[arg(
+        long = "global",
+        short = 'g',
+        help = "use the global profile store instead of the project's",
+        global = true
+    )]
+    pub global: bool,
+    #[arg(
+        long = "no-input",
+        visible_alias = "yes",
+        short = 'y',
+        help = "never prompt, use defaults and fail when input is required (env: ENVIO_PROMPT_NO_INPUT)",
+        global = true
+    )]
+    pub no_input: bool,
+    #[arg(
+        long = "output",
+        value_parser = ["table", "plain", "json", "yaml"],
+        help = "output format of list, show, check, lint, grep, scan, version and --diagnostic (default: table)",
+        global = true
+    )]
+    pub output: Option<String>,
 }
 
 #[derive(clap::Subcommand, Debug)]
@@ -63,6 +91,27 @@ pub enum Command {
             help = "add expiration dates to the provided environment variables"
         )]
         expires: bool,
+        #[arg(
+            long = "no-comments",
+            conflicts_with = "comments",
+            help = "don't ask for comments, overriding create.comments from the config"
+        )]
+        no_comments: bool,
+        #[arg(
+            long = "no-expires",
+            conflicts_with = "expires",
+            help = "don't ask for expiration dates, overriding create.expires from the config"
+        )]
+        no_expires: bool,
+        #[arg(
+            long = "secret",
+            short = 's',
+            num_args = 1..,
+            value_delimiter = ',',
+            value_name = "KEYS",
+            help = "mark these variables as secret, their values are masked when displayed"
+        )]
+        secret: Vec<String>,
     },
 
     #[command(
@@ -76,6 +125,52 @@ pub enum Command {
         profile_name: String,
     },
 
+    #[command(
+        name = "rename",
+        about = "Rename a profile",
+        visible_aliases = &["mv"],
+        override_usage = "envio rename <PROFILE_NAME> <NEW_NAME>"
+    )]
+    Rename {
+        #[arg(required = true, help = "name of the profile")]
+        profile_name: String,
+        #[arg(
+            required = true,
+            help = "new name, prefix it with ~ to move the profile to the global store"
+        )]
+        new_name: String,
+    },
+
+    #[command(
+        name = "copy",
+        about = "Copy a profile under a new name and uuid",
+        visible_aliases = &["cp"],
+        override_usage = "envio copy <PROFILE_NAME> <NEW_NAME> [OPTIONS]"
+    )]
+    Copy {
+        #[arg(required = true, help = "name of the profile")]
+        profile_name: String,
+        #[arg(required = true, help = "name of the copy")]
+        new_name: String,
+        #[arg(
+            long = "cipher-kind",
+            short = 'k',
+            help = "re-encrypt the copy with this cipher"
+        )]
+        cipher_kind: Option<String>,
+        #[arg(
+            long = "new-key",
+            help = "re-encrypt the copy under a new key with the same cipher"
+        )]
+        new_key: bool,
+        #[arg(
+            long = "description",
+            short = 'd',
+            help = "description of the copy (default: the one of the profile)"
+        )]
+        description: Option<String>,
+    },
+
     #[command(
         name = "list",
         about = "List all profiles",
@@ -90,11 +185,11 @@ pub enum Command {
     #[command(
         name = "show",
         about = "Show environment variables in a profile",
-        override_usage = "envio show <PROFILE_NAME> [OPTIONS]"
+        override_usage = "envio show [PROFILE_NAME] [OPTIONS]"
     )]
     Show {
-        #[arg(required = true, help = "name of the profile")]
-        profile_name: String,
+        #[arg(help = "name of the profile (default: the one set with `envio use`)")]
+        profile_name: Option<String>,
         #[arg(long = "show-comments", short = 'c', help = "display comments")]
         show_comments: bool,
         #[arg(
@@ -105,16 +200,119 @@ pub enum Command {
         show_expiration: bool,
         #[arg(long = "no-pretty-print", help = "disable pretty printing")]
         no_pretty_print: bool,
+        #[arg(
+            long = "show-metadata",
+            short = 'm',
+            help = "display tags, labels and when each variable was last updated"
+        )]
+        show_metadata: bool,
+        #[arg(
+            long = "tag",
+            value_delimiter = ',',
+            help = "comma-separated list of tags, only variables with one of them are shown"
+        )]
+        tags: Vec<String>,
+        #[arg(
+            long = "reveal",
+            num_args = 0..=1,
+            require_equals = true,
+            value_delimiter = ',',
+            value_name = "KEYS",
+            help = "display secret values, all of them or only the given keys (format: --reveal=KEY,...)"
+        )]
+        reveal: Option<Vec<String>>,
+    },
+
+    #[command(
+        name = "grep",
+        about = "Search every profile for a key, value or comment",
+        override_usage = "envio grep <PATTERN> [OPTIONS]"
+    )]
+    Grep {
+        #[arg(required = true, help = "text to search for")]
+        pattern: String,
+        #[arg(
+            long = "keys",
+            help = "search the keys (default: keys, values and comments)"
+        )]
+        keys: bool,
+        #[arg(long = "values", help = "search the values")]
+        values: bool,
+        #[arg(long = "comments", help = "search the comments")]
+        comments: bool,
+        #[arg(
+            long = "regex",
+            short = 'E',
+            help = "treat the pattern as a regular expression"
+        )]
+        regex: bool,
+        #[arg(long = "ignore-case", short = 'i', help = "match regardless of case")]
+        ignore_case: bool,
+    },
+
+    #[command(
+        name = "scan",
+        about = "Search the project's files for values of its profiles",
+        override_usage = "envio scan [PATHS]... [OPTIONS]"
+    )]
+    Scan {
+        #[arg(help = "files or folders to scan (default: every file git doesn't ignore)")]
+        paths: Vec<String>,
+        #[arg(
+            long = "staged",
+            help = "scan the content staged for the next commit, for a pre-commit hook"
+        )]
+        staged: bool,
+        #[arg(
+            long = "min-length",
+            default_value_t = 8,
+            value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..),
+            help = "ignore values shorter than this, at least 1"
+        )]
+        min_length: usize,
+    },
+
+    #[command(
+        name = "lint",
+        about = "Check profiles for invalid names, leftover quotes, placeholders and similar problems",
+        override_usage = "envio lint [PROFILE_NAMES]... [OPTIONS]"
+    )]
+    Lint {
+        #[arg(help = "profiles to lint (default: every profile)")]
+        profile_names: Vec<String>,
+        #[arg(
+            long = "fix",
+            help = "fix what can be fixed without asking and save the profiles"
+        )]
+        fix: bool,
+        #[arg(
+            long = "disable",
+            value_name = "RULE",
+            value_delimiter = ',',
+            help = "skip a rule, can be repeated"
+        )]
+        disable: Vec<String>,
+        #[arg(long = "strict", help = "fail on warnings too")]
+        strict: bool,
+        #[arg(long = "list-rules", help = "list the rules and exit")]
+        list_rules: bool,
     },
 
     #[command(
         name = "check",
-        about = "Check for expired and upcoming environment variables in a profile",
-        override_usage = "envio check <PROFILE_NAME>"
+        about = "Check a profile for expired variables and against the project's schema",
+        override_usage = "envio check [PROFILE_NAME] [OPTIONS]"
     )]
     Check {
-        #[arg(required = true, help = "name of the profile")]
-        profile_name: String,
+        #[arg(help = "name of the profile (default: the one set with `envio use`)")]
+        profile_name: Option<String>,
+        #[arg(
+            long = "all",
+            short = 'a',
+            conflicts_with = "profile_name",
+            help = "check every profile"
+        )]
+        all: bool,
     },
 
     #[command(
@@ -130,13 +328,16 @@ pub enum Command {
     #[command(
         name = "set",
         about = "Set or update environment variables in a profile",
-        override_usage = "envio set <PROFILE_NAME> <ENVS>... [OPTIONS]"
+        override_usage = "envio set [PROFILE_NAME] <ENVS>... [OPTIONS]"
     )]
     Set {
-        #[arg(required = true, help = "name of the profile")]
-        profile_name: String,
-        #[arg(required = true, num_args = 1.., help = "environment variables to set (format: KEY=VALUE or only provide KEY and the value will be prompted for)")]
-        envs: Vec<String>,
+        #[arg(
+            required = true,
+            num_args = 1..,
+            value_name = "ENVS",
+            help = "name of the profile (default: the one set with `envio use`), followed by the environment variables to set (format: KEY=VALUE or only provide KEY and the value will be prompted for)"
+        )]
+        args: Vec<String>,
         #[arg(
             long = "comments",
             short = 'c',
@@ -149,38 +350,225 @@ pub enum Command {
             help = "add expiration dates to the provided environment variables"
         )]
         expires: bool,
+        #[arg(
+            long = "secret",
+            short = 's',
+            help = "mark the provided environment variables as secret, their values are masked when displayed"
+        )]
+        secret: bool,
+        #[arg(
+            long = "plain",
+            conflicts_with = "secret",
+            help = "mark the provided environment variables as plain, their values are displayed"
+        )]
+        plain: bool,
+        #[arg(
+            long = "tag",
+            short = 't',
+            value_delimiter = ',',
+            help = "comma-separated list of tags replacing those of the provided environment variables"
+        )]
+        tags: Option<Vec<String>>,
+        #[arg(
+            long = "label",
+            short = 'l',
+            value_name = "KEY=VALUE",
+            help = "label to add to the provided environment variables, can be repeated (an empty value removes the label)"
+        )]
+        labels: Vec<String>,
+        #[arg(
+            long = "rotate-every",
+            value_name = "INTERVAL",
+            help = "how often the provided environment variables should be rotated, e.g. 90d or 12w (off removes the interval)"
+        )]
+        rotate_every: Option<String>,
+        #[arg(
+            long = "rotate-command",
+            value_name = "COMMAND",
+            help = "shell command printing a new value for `envio rotate-var`, it gets the old value on stdin (an empty command removes it)"
+        )]
+        rotate_command: Option<String>,
     },
 
     #[command(
         name = "unset",
         about = "Remove environment variables from a profile",
-        override_usage = "envio unset <PROFILE_NAME> <KEYS>... [OPTIONS]"
+        override_usage = "envio unset [PROFILE_NAME] <KEYS>... [OPTIONS]"
     )]
     Unset {
+        #[arg(
+            required = true,
+            value_delimiter = ' ',
+            num_args = 1..,
+            value_name = "KEYS",
+            help = "name of the profile (default: the one set with `envio use`), followed by the keys of environment variables to remove"
+        )]
+        args: Vec<String>,
+    },
+
+    #[command(
+        name = "mv-var",
+        about = "Move environment variables from one profile to another",
+        override_usage = "envio mv-var <FROM> <TO> <KEYS>... [OPTIONS]"
+    )]
+    MvVar {
+        #[arg(required = true, help = "profile to take the variables from")]
+        from: String,
+        #[arg(required = true, help = "profile to move the variables to")]
+        to: String,
+        #[arg(required = true, num_args = 1.., help = "keys of the variables to move")]
+        keys: Vec<String>,
+        #[arg(
+            long = "force",
+            short = 'f',
+            help = "overwrite variables that already exist in <TO>"
+        )]
+        force: bool,
+    },
+
+    #[command(
+        name = "cp-var",
+        about = "Copy environment variables from one profile to another",
+        override_usage = "envio cp-var <FROM> <TO> <KEYS>... [OPTIONS]"
+    )]
+    CpVar {
+        #[arg(required = true, help = "profile to copy the variables from")]
+        from: String,
+        #[arg(required = true, help = "profile to copy the variables to")]
+        to: String,
+        #[arg(required = true, num_args = 1.., help = "keys of the variables to copy")]
+        keys: Vec<String>,
+        #[arg(
+            long = "force",
+            short = 'f',
+            help = "overwrite variables that already exist in <TO>"
+        )]
+        force: bool,
+    },
+
+    #[command(
+        name = "rotate-var",
+        about = "Replace the value of a variable, keeping the old one in the profile",
+        override_usage = "envio rotate-var <PROFILE_NAME> <KEY> [OPTIONS]"
+    )]
+    RotateVar {
         #[arg(required = true, help = "name of the profile")]
         profile_name: String,
-        #[arg(required = true, value_delimiter = ' ', num_args = 1.., help = "keys of environment variables to remove")]
-        keys: Vec<String>,
+        #[arg(required = true, help = "key of the variable to rotate")]
+        key: String,
+        #[arg(
+            long = "expires",
+            short = 'x',
+            value_name = "DATE",
+            conflicts_with = "rollback",
+            help = "new expiration date (format: YYYY-MM-DD, default: today plus the rotation interval)"
+        )]
+        expires: Option<String>,
+        #[arg(
+            long = "rollback",
+            help = "restore the value replaced by the last rotation"
+        )]
+        rollback: bool,
+        #[arg(
+            long = "run-command",
+            conflicts_with = "rollback",
+            help = "run the rotation command stored in the profile without asking, --no-input doesn't imply it"
+        )]
+        run_command: bool,
+    },
+
+    #[command(
+        name = "use",
+        about = "Set the default profile of the project, used when a command is not given one",
+        override_usage = "envio use [PROFILE_NAME] [OPTIONS]"
+    )]
+    Use {
+        #[arg(
+            conflicts_with = "clear",
+            help = "name of the profile, prints the current default if omitted"
+        )]
+        profile_name: Option<String>,
+        #[arg(long = "clear", help = "remove the default profile")]
+        clear: bool,
     },
 
     #[command(
         name = "shell",
         about = "Spawn a new shell with environment variables loaded from the profile",
-        override_usage = "envio shell <PROFILE_NAME> [OPTIONS]"
+        override_usage = "envio shell [PROFILE_NAME] [OPTIONS]"
     )]
     Shell {
-        #[arg(required = true, help = "name of the profile")]
-        profile_name: String,
+        #[arg(help = "name of the profile (default: the one set with `envio use`)")]
+        profile_name: Option<String>,
+        #[command(flatten)]
+        environment: EnvironmentArgs,
     },
 
     #[command(
         name = "run",
         about = "Run a command using environment variables from a profile",
-        override_usage = "envio run <PROFILE_NAME> [OPTIONS] -- <COMMAND>"
+        override_usage = "envio run [PROFILE_NAME] [OPTIONS] -- <COMMAND>"
     )]
     Run {
-        #[arg(required = true, help = "name of the profile")]
-        profile_name: String,
+        #[arg(help = "name of the profile (default: the one set with `envio use`)")]
+        profile_name: Option<String>,
+        #[command(flatten)]
+        environment: EnvironmentArgs,
+        #[arg(
+            long = "exec",
+            help = "replace envio with the command instead of running it as a child process (unix only)"
+        )]
+        exec: bool,
+        #[arg(
+            long = "redact",
+            conflicts_with = "exec",
+            help = "replace profile values in the command's output with ***"
+        )]
+        redact: bool,
+        #[arg(
+            long = "no-redact",
+            conflicts_with = "redact",
+            help = "don't redact the command's output, overriding run.redact from the config"
+        )]
+        no_redact: bool,
+        #[arg(
+            long = "no-clean",
+            conflicts_with = "clean",
+            help = "keep the current environment, overriding run.clean from the config"
+        )]
+        no_clean: bool,
+        #[arg(
+            long = "redact-encoded",
+            help = "also redact the base64 and URL-encoded forms of profile values, with --redact or run.redact"
+        )]
+        redact_encoded: bool,
+        #[arg(
+            long = "watch",
+            short = 'w',
+            conflicts_with = "exec",
+            help = "restart the command when one of the profiles changes"
+        )]
+        watch: bool,
+        #[arg(
+            long = "restart-signal",
+            requires = "watch",
+            default_value = "TERM",
+            help = "signal sent to the command before restarting it"
+        )]
+        restart_signal: String,
+        #[arg(
+            long = "grace-period",
+            requires = "watch",
+            default_value_t = 10,
+            help = "seconds to wait for the command to exit before killing it"
+        )]
+        grace_period: u64,
+        #[arg(
+            long = "on-change",
+            requires = "watch",
+            help = "run this shell command with the new environment instead of restarting"
+        )]
+        on_change: Option<String>,
         #[arg(last = true, required = true, help = "command to run")]
         command: Vec<String>,
     },
@@ -204,11 +592,11 @@ pub enum Command {
     #[command(
         name = "export",
         about = "Export the environment variables of a profile to a file",
-        override_usage = "envio export <PROFILE_NAME> [OPTIONS]"
+        override_usage = "envio export [PROFILE_NAME] [OPTIONS]"
     )]
     Export {
-        #[arg(required = true, help = "name of the profile")]
-        profile_name: String,
+        #[arg(help = "name of the profile (default: the one set with `envio use`)")]
+        profile_name: Option<String>,
         #[arg(
             long = "output-file-path",
             short = 'o',
@@ -226,11 +614,16 @@ pub enum Command {
         #[arg(
             long = "format",
             short = 'f',
-            default_value = "dotenv",
             value_parser = ["dotenv", "json", "yaml", "shell"],
-            help = "output format (dotenv, json, yaml, shell)"
+            help = "output format (dotenv, json, yaml, shell) (default: dotenv)"
         )]
-        format: String,
+        format: Option<String>,
+        #[arg(
+            long = "tag",
+            value_delimiter = ',',
+            help = "comma-separated list of tags, only variables with one of them are exported"
+        )]
+        tags: Vec<String>,
     },
 
     #[command(
@@ -284,6 +677,120 @@ pub enum Command {
         shell: String,
     },
 
+    #[command(
+        name = "hook",
+        about = "Print a shell hook that loads the active profile when entering a directory",
+        override_usage = "envio hook <SHELL>"
+    )]
+    Hook {
+        #[arg(
+            required = true,
+            help = "shell to print the hook for (bash, zsh, fish)",
+            value_parser = clap::builder::PossibleValuesParser::new(&["bash", "zsh", "fish"])
+        )]
+        shell: String,
+    },
+
+    #[command(name = "hook-env", hide = true)]
+    HookEnv {
+        #[arg(
+            required = true,
+            value_parser = clap::builder::PossibleValuesParser::new(&["bash", "zsh", "fish"])
+        )]
+        shell: String,
+    },
+
+    #[command(
+        name = "allow",
+        about = "Allow the shell hook to load the active profile of a directory",
+        override_usage = "envio allow [DIR] [OPTIONS]"
+    )]
+    Allow {
+        #[arg(help = "directory to allow (default: the current project)")]
+        dir: Option<String>,
+        #[arg(long = "revoke", help = "revoke a previous approval instead")]
+        revoke: bool,
+    },
+
+    #[command(
+        name = "config",
+        about = "Get and set envio settings",
+        override_usage = "envio config <COMMAND> [OPTIONS]"
+    )]
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+
+    #[command(
+        name = "migrate",
+        about = "Move the profiles to another storage backend and switch to it",
+        override_usage = "envio migrate <BACKEND> [OPTIONS]"
+    )]
+    Migrate {
+        #[arg(
+            value_parser = ["file", "sqlite"],
+            help = "storage backend to move the profiles to"
+        )]
+        backend: String,
+    },
+
+    #[command(
+        name = "backup",
+        about = "Write every profile and the config to one encrypted archive",
+        override_usage = "envio backup [OPTIONS]"
+    )]
+    Backup {
+        #[arg(
+            long = "output-file-path",
+            short = 'o',
+            help = "archive to write (default: envio-<date>.envio-backup)"
+        )]
+        output_file_path: Option<String>,
+        #[arg(
+            long = "include-keys",
+            short = 'i',
+            help = "also back up keyring keys, asks which profiles unless prompts are disabled"
+        )]
+        include_keys: bool,
+        #[arg(
+            long = "recipient",
+            short = 'r',
+            help = "gpg key fingerprint to encrypt the archive for instead of a passphrase"
+        )]
+        recipient: Option<String>,
+        #[arg(long = "force", short = 'f', help = "overwrite an existing archive")]
+        force: bool,
+    },
+
+    #[command(
+        name = "restore",
+        about = "Restore profiles, config and keys from a backup archive",
+        override_usage = "envio restore <ARCHIVE> [OPTIONS]"
+    )]
+    Restore {
+        #[arg(required = true, help = "archive written by `envio backup`")]
+        archive: String,
+        #[arg(long = "dry-run", help = "only list what would be restored")]
+        dry_run: bool,
+        #[arg(
+            long = "on-conflict",
+            value_parser = ["skip", "overwrite", "rename"],
+            help = "what to do with profiles that already exist (default: ask)"
+        )]
+        on_conflict: Option<String>,
+    },
+
+    #[command(
+        name = "schema",
+        about = "Manage the schema of the variables the project needs",
+        override_usage = "envio schema <COMMAND> [OPTIONS]"
+    )]
+    Schema {
+        #[command(subcommand)]
+        action: SchemaAction,
+    },
+
     #[command(
         name = "version",
         about = "Print version information",
@@ -298,3 +805,124 @@ pub enum Command {
         verbose: bool,
     },
 }
+
+#[derive(clap::Subcommand, Debug)]
+pub enum SchemaAction {
+    #[command(
+        name = "generate",
+        about = "Write .envio/schema.toml listing the variables of a profile",
+        override_usage = "envio schema generate [PROFILE_NAME] [OPTIONS]"
+    )]
+    Generate {
+        #[arg(help = "name of the profile (default: the one set with `envio use`)")]
+        profile_name: Option<String>,
+        #[arg(long = "force", short = 'f', help = "overwrite an existing schema")]
+        force: bool,
+    },
+
+    #[command(
+        name = "example",
+        about = "Write a .env.example with the keys and descriptions of the schema, without secrets",
+        override_usage = "envio schema example [OPTIONS]"
+    )]
+    Example {
+        #[arg(
+            long = "output-file-path",
+            short = 'o',
+            help = "output file path (default: .env.example next to .envio)"
+        )]
+        output_file_path: Option<String>,
+    },
+}
+
+#[derive(clap::Subcommand, Debug)]
+pub enum ConfigAction {
+    #[command(
+        name = "get",
+        about = "Print the effective value of a setting",
+        override_usage = "envio config get <KEY>"
+    )]
+    Get {
+        #[arg(required = true, help = "setting to print, e.g. create.cipher")]
+        key: String,
+    },
+
+    #[command(
+        name = "set",
+        about = "Set a setting in the project config, or the user config with --global",
+        override_usage = "envio config set <KEY> <VALUE> [OPTIONS]"
+    )]
+    Set {
+        #[arg(required = true, help = "setting to change, e.g. create.cipher")]
+        key: String,
+        #[arg(required = true, help = "new value, lists are comma-separated")]
+        value: String,
+    },
+
+    #[command(
+        name = "unset",
+        about = "Remove a setting from the project config, or the user config with --global",
+        override_usage = "envio config unset <KEY> [OPTIONS]"
+    )]
+    Unset {
+        #[arg(required = true, help = "setting to remove")]
+        key: String,
+    },
+
+    #[command(
+        name = "list",
+        about = "List every setting with its value and where it comes from",
+        visible_aliases = &["ls"],
+        override_usage = "envio config list"
+    )]
+    List,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct EnvironmentArgs {
+    #[arg(
+        long = "profile",
+        short = 'p',
+        help = "additional profile to load, can be repeated (later profiles override earlier ones)"
+    )]
+    pub profiles: Vec<String>,
+    #[arg(
+        long = "clean",
+        help = "start from an empty environment, only keeping essentials such as PATH, HOME and TERM"
+    )]
+    pub clean: bool,
+    #[arg(
+        long = "keep",
+        value_delimiter = ',',
+        help = "comma-separated list of extra variables to keep from the current environment with --clean"
+    )]
+    pub keep: Vec<String>,
+    #[arg(
+        long = "only",
+        value_delimiter = ',',
+        help = "comma-separated list of keys to load from the profile ('*' matches any characters)"
+    )]
+    pub only: Vec<String>,
+    #[arg(
+        long = "except",
+        value_delimiter = ',',
+        help = "comma-separated list of keys to skip from the profile ('*' matches any characters)"
+    )]
+    pub except: Vec<String>,
+    #[arg(
+        long = "tag",
+        value_delimiter = ',',
+        help = "comma-separated list of tags, only variables with one of them are loaded from the profile"
+    )]
+    pub tags: Vec<String>,
+    #[arg(
+        long = "prefix",
+        help = "prefix to add to every key loaded from the profile"
+    )]
+    pub prefix: Option<String>,
+    #[arg(
+        long = "strip-prefix",
+        help = "prefix to remove from keys loaded from the profile"
+    )]
+    pub strip_prefix: Option<String>,
+}
@@ -0,0 +1,37 @@
+use std::path::PathBuf;
+
+use crate::{
+    error::{AppError, AppResult},
+    hook, success_msg, utils, warning_msg,
+};
+
+pub fn run(dir: Option<&str>, revoke: bool) -> AppResult<()> {
+    let start = dir.map(PathBuf::from).unwrap_or_else(utils::get_cwd);
+    let start = start.canonicalize()?;
+
+    let project = hook::find_project(&start).ok_or_else(|| {
+        AppError::Msg(format!(
+            "No .envio folder with an active profile found in {} or its parents",
+            start.display()
+        ))
+    })?;
+
+    if revoke {
+        if hook::revoke(&project)? {
+            success_msg!("Revoked {}", project.display());
+        } else {
+            warning_msg!("{} was not allowed", project.display());
+        }
+
+        return Ok(());
+    }
+
+    hook::allow(&project)?;
+    success_msg!(
+        "Allowed profile `{}` in {}",
+        hook::active_profile(&project)?,
+        project.display()
+    );
+
+    Ok(())
+}
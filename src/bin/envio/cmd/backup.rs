@@ -0,0 +1,433 @@
+use std::path::PathBuf;
+
+use envio::{
+    backup::Backup,
+    cipher::{Cipher, CipherKind, GPG, PASSPHRASE},
+    store::ProfileStore,
+};
+use zeroize::Zeroizing;
+
+use crate::{
+    config,
+    error::{AppError, AppResult},
+    prompts, schema,
+    settings::CONFIG_FILE,
+    success_msg, warning_msg,
+};
+
+/// files of the `.envio` folder that are backed up next to the profiles
+const BACKED_UP_FILES: [&str; 3] = [CONFIG_FILE, schema::SCHEMA_FILE, config::ACTIVE_FILE];
+
+const ARCHIVE_EXTENSION: &str = "envio-backup";
+
+pub fn backup(
+    output_file_path: Option<&str>,
+    include_keys: bool,
+    recipient: Option<&str>,
+    force: bool,
+    global: bool,
+) -> AppResult<()> {
+    let envio_dir = envio_dir(global)?;
+
+    let path = output_file_path.map(PathBuf::from).unwrap_or_else(|| {
+        PathBuf::from(format!(
+            "envio-{}.{}",
+            chrono::Local::now().format("%Y-%m-%d"),
+            ARCHIVE_EXTENSION
+        ))
+    });
+
+    if path.exists() && !force {
+        return Err(AppError::Msg(format!(
+            "`{}` already exists, pass --force to overwrite it",
+            path.display()
+        )));
+    }
+
+    let store = config::open_store(&envio_dir)?;
+    let mut backup = Backup::default();
+
+    for name in store.list()? {
+        let mut profile = store.load(&name)?;
+        profile.metadata.name = name;
+        backup.profiles.push(profile);
+    }
+
+    for file in BACKED_UP_FILES {
+        let file_path = envio_dir.join(file);
+        if file_path.is_file() {
+            backup
+                .files
+                .insert(file.to_string(), std::fs::read_to_string(file_path)?);
+        }
+    }
+
+    if include_keys {
+        for (uuid, key) in select_keys(&backup)? {
+            backup.keys.insert(uuid, key);
+        }
+    }
+
+    let cipher: Box<dyn Cipher> = match recipient {
+        Some(fingerprint) => Box::new(GPG::new(fingerprint.to_string())),
+        None => Box::new(PASSPHRASE::new(backup_key(true)?)),
+    };
+
+    std::fs::write(&path, backup.seal(cipher)?)?;
+
+    success_msg!(
+        "Backed up {} profile(s), {} file(s) and {} key(s) to {}",
+        backup.profiles.len(),
+        backup.files.len(),
+        backup.keys.len(),
+        path.display()
+    );
+
+    Ok(())
+}
+
+/// keyring keys of the backed up profiles the user wants in the archive, all of
+/// them when prompts are disabled
+fn select_keys(backup: &Backup) -> AppResult<Vec<(String, Zeroizing<String>)>> {
+    let available: Vec<_> = backup
+        .profiles
+        .iter()
+        .filter(|profile| {
+            matches!(
+                profile.metadata.cipher_kind,
+                CipherKind::PASSPHRASE | CipherKind::SYMMETRIC
+            )
+        })
+        .filter_map(|profile| {
+            let key = keyring::Entry::new("envio", &profile.metadata.uuid)
+                .ok()?
+                .get_password()
+                .ok()?;
+
+            Some((
+                profile.metadata.name.clone(),
+                profile.metadata.uuid.clone(),
+                Zeroizing::new(key),
+            ))
+        })
+        .collect();
+
+    if available.is_empty() {
+        warning_msg!("None of the profiles have a key in the keyring");
+        return Ok(Vec::new());
+    }
+
+    let names = prompts::multi_select_prompt(prompts::MultiSelectPromptOptions {
+        title: "Select the profiles whose keyring key to back up:".to_string(),
+        options: available.iter().map(|(name, _, _)| name.clone()).collect(),
+        default_indices: Some((0..available.len()).collect()),
+    })?;
+
+    Ok(available
+        .into_iter()
+        .filter(|(name, _, _)| names.contains(name))
+        .map(|(_, uuid, key)| (uuid, key))
+        .collect())
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Conflict {
+    Skip,
+    Overwrite,
+    Rename,
+}
+
+impl Conflict {
+    fn parse(value: &str) -> AppResult<Self> {
+        match value {
+            "skip" => Ok(Self::Skip),
+            "overwrite" => Ok(Self::Overwrite),
+            "rename" => Ok(Self::Rename),
+            _ => Err(AppError::Msg(format!(
+                "Unsupported conflict handling: {}",
+                value
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for Conflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Skip => write!(f, "skip"),
+            Self::Overwrite => write!(f, "overwrite"),
+            Self::Rename => write!(f, "rename"),
+        }
+    }
+}
+
+enum Action {
+    Restore,
+    Skip,
+    Overwrite,
+    Rename(String),
+}
+
+impl std::fmt::Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Restore => write!(f, "restore"),
+            Self::Skip => write!(f, "skip, already exists"),
+            Self::Overwrite => write!(f, "overwrite"),
+            Self::Rename(name) => write!(f, "restore as `{}`", name),
+        }
+    }
+}
+
+pub fn restore(
+    archive: &str,
+    dry_run: bool,
+    on_conflict: Option<&str>,
+    global: bool,
+) -> AppResult<()> {
+    let on_conflict = on_conflict.map(Conflict::parse).transpose()?;
+
+    let bytes = std::fs::read(archive)?;
+    let key = match Backup::cipher_kind(&bytes)? {
+        CipherKind::PASSPHRASE => Some(backup_key(false)?),
+        _ => None,
+    };
+    let backup = Backup::open(&bytes, key)?;
+    check_names(&backup)?;
+
+    let envio_dir = if global {
+        config::get_global_dir()
+    } else {
+        // restoring into a fresh checkout creates the folder
+        config::get_envio_dir().unwrap_or_else(|_| config::init_dir())
+    };
+
+    // the config decides the storage backend, so it is restored first
+    let mut files = Vec::new();
+    for (file, contents) in &backup.files {
+        let path = envio_dir.join(file);
+        let differs = path.is_file() && std::fs::read_to_string(&path)? != *contents;
+
+        let action = match resolve(
+            differs,
+            on_conflict,
+            dry_run,
+            &format!("File '{}'", file),
+            &[Conflict::Skip, Conflict::Overwrite],
+        )? {
+            Some(Conflict::Overwrite) => Action::Overwrite,
+            Some(_) => Action::Skip,
+            None => Action::Restore,
+        };
+
+        if dry_run {
+            println!("file {}: {}", file, action);
+        } else if !matches!(action, Action::Skip) {
+            std::fs::create_dir_all(&envio_dir)?;
+            std::fs::write(&path, contents)?;
+            files.push(file);
+        }
+    }
+
+    let store = config::open_store(&envio_dir)?;
+    let existing_uuids: Vec<String> = store
+        .list_metadata()?
+        .into_iter()
+        .map(|metadata| metadata.uuid)
+        .collect();
+
+    // names picked for renamed profiles, a dry run doesn't save them
+    let mut planned: Vec<String> = Vec::new();
+    // keyring entries of overwritten profiles whose uuid the restored one doesn't keep
+    let mut replaced_uuids: Vec<String> = Vec::new();
+    let mut restored = 0;
+    let mut keys = 0;
+
+    for profile in &backup.profiles {
+        let name = &profile.metadata.name;
+        let exists = store.exists(name)?;
+
+        let action = match resolve(
+            exists,
+            on_conflict,
+            dry_run,
+            &format!("Profile '{}'", name),
+            &[Conflict::Skip, Conflict::Overwrite, Conflict::Rename],
+        )? {
+            Some(Conflict::Skip) => Action::Skip,
+            Some(Conflict::Overwrite) => Action::Overwrite,
+            Some(Conflict::Rename) => Action::Rename(free_name(store.as_ref(), name, &planned)?),
+            None => Action::Restore,
+        };
+
+        if let Action::Rename(new_name) = &action {
+            planned.push(new_name.clone());
+        }
+
+        let key = backup.keys.get(&profile.metadata.uuid);
+
+        if dry_run {
+            let key_note = match (&action, key) {
+                (Action::Skip, _) | (_, None) => "",
+                (_, Some(_)) => ", with its keyring key",
+            };
+            println!("profile {}: {}{}", name, action, key_note);
+            continue;
+        }
+
+        let mut profile = profile.clone();
+
+        match action {
+            Action::Skip => continue,
+            Action::Rename(new_name) => {
+                // a copy next to the original may not share its keyring entry
+                if existing_uuids.contains(&profile.metadata.uuid) {
+                    profile.metadata.uuid = uuid::Uuid::new_v4().to_string();
+                }
+                profile.metadata.name = new_name;
+            }
+            Action::Overwrite => {
+                let previous = store.load_metadata(name)?.uuid;
+                if previous != profile.metadata.uuid {
+                    replaced_uuids.push(previous);
+                }
+            }
+            Action::Restore => {}
+        }
+
+        store.save(&profile.metadata.name, &profile)?;
+        restored += 1;
+
+        if let Some(key) = key {
+            keyring::Entry::new("envio", &profile.metadata.uuid)
+                .and_then(|entry| entry.set_password(key.as_str()))
+                .map_err(|e| AppError::Msg(format!("Failed to store key in keyring: {}", e)))?;
+            keys += 1;
+        }
+    }
+
+    if !replaced_uuids.is_empty() {
+        let in_use: Vec<String> = store
+            .list_metadata()?
+            .into_iter()
+            .map(|metadata| metadata.uuid)
+            .collect();
+
+        for uuid in replaced_uuids.iter().filter(|uuid| !in_use.contains(uuid)) {
+            if let Ok(entry) = keyring::Entry::new("envio", uuid) {
+                let _ = entry.delete_credential();
+            }
+        }
+    }
+
+    if !dry_run {
+        success_msg!(
+            "Restored {} of {} profile(s), {} file(s) and {} key(s)",
+            restored,
+            backup.profiles.len(),
+            files.len(),
+            keys
+        );
+    }
+
+    Ok(())
+}
+
+/// the file and profile names of the archive are used as paths, so anything that
+/// could point outside the envio folder is rejected before restoring
+fn check_names(backup: &Backup) -> AppResult<()> {
+    if let Some(file) = backup
+        .files
+        .keys()
+        .find(|file| !BACKED_UP_FILES.contains(&file.as_str()))
+    {
+        return Err(AppError::Msg(format!(
+            "The backup contains an unexpected file '{}', nothing was restored",
+            file
+        )));
+    }
+
+    if let Some(profile) = backup.profiles.iter().find(|profile| {
+        let name = &profile.metadata.name;
+        name.is_empty() || name == "." || name == ".." || config::contains_path_separator(name)
+    }) {
+        return Err(AppError::Msg(format!(
+            "The backup contains an invalid profile name '{}', nothing was restored",
+            profile.metadata.name
+        )));
+    }
+
+    Ok(())
+}
+
+/// how to handle `what` when it already exists, asks when `--on-conflict` isn't
+/// given and `None` when there is no conflict, files are never renamed
+fn resolve(
+    exists: bool,
+    on_conflict: Option<Conflict>,
+    dry_run: bool,
+    what: &str,
+    choices: &[Conflict],
+) -> AppResult<Option<Conflict>> {
+    if !exists {
+        return Ok(None);
+    }
+
+    if let Some(on_conflict) = on_conflict {
+        return Ok(Some(on_conflict));
+    }
+
+    // a dry run never changes anything, so there is nothing to decide yet
+    if dry_run {
+        return Ok(Some(Conflict::Skip));
+    }
+
+    prompts::select_prompt(prompts::SelectPromptOptions {
+        title: format!("{} already exists:", what),
+        options: choices.to_vec(),
+        flag: Some("--on-conflict"),
+    })
+    .map(Some)
+}
+
+/// `<name>-restored`, numbered when that is taken too
+fn free_name(store: &dyn ProfileStore, name: &str, planned: &[String]) -> AppResult<String> {
+    let taken = |candidate: &str| -> AppResult<bool> {
+        Ok(planned.iter().any(|name| name == candidate) || store.exists(candidate)?)
+    };
+
+    let mut candidate = format!("{}-restored", name);
+    let mut n = 2;
+
+    while taken(&candidate)? {
+        candidate = format!("{}-restored-{}", name, n);
+        n += 1;
+    }
+
+    Ok(candidate)
+}
+
+fn envio_dir(global: bool) -> AppResult<PathBuf> {
+    if global {
+        Ok(config::get_global_dir())
+    } else {
+        config::get_envio_dir()
+    }
+}
+
+/// the passphrase of the archive, from `ENVIO_BACKUP_KEY` or a prompt
+fn backup_key(with_confirmation: bool) -> AppResult<Zeroizing<String>> {
+    if let Ok(key) = std::env::var("ENVIO_BACKUP_KEY") {
+        return Ok(Zeroizing::new(key));
+    }
+
+    prompts::password_prompt(prompts::PasswordPromptOptions {
+        title: "Enter the backup passphrase:".to_string(),
+        help_message: Some("It is separate from the keys of the profiles".to_string()),
+        min_length: None,
+        with_confirmation,
+        confirmation_error_message: None,
+        flag: Some("ENVIO_BACKUP_KEY"),
+    })
+    .map(Zeroizing::new)
+}
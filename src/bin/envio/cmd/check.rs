@@ -1,15 +1,176 @@
-use chrono::Local;
+use chrono::{Local, NaiveDate};
 use colored::Colorize;
 use comfy_table::{Attribute, Cell, Color, ContentArrangement, Table};
-use envio::get_profile;
+use serde::Serialize;
 
-use crate::{config, error::AppResult, profile_ops};
+use crate::{
+    error::{AppError, AppResult},
+    output::{self, OutputFormat},
+    profile_ops,
+    schema::{self, Violation, ViolationKind},
+};
 
-pub fn run(profile_name: &str) -> AppResult<()> {
-    let profile = get_profile(
-        config::get_profile_path(profile_name)?,
-        Some(profile_ops::resolve_key),
-    )?;
+#[derive(Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Status {
+    Expired,
+    Upcoming,
+}
+
+#[derive(Serialize)]
+struct ExpirationStatus<'a> {
+    key: &'a str,
+    status: Status,
+    expiration_date: NaiveDate,
+    /// days until the variable expires, negative once it has expired
+    days_remaining: i64,
+}
+
+impl ExpirationStatus<'_> {
+    fn time_description(&self) -> String {
+        match (self.status, self.days_remaining) {
+            (Status::Expired, 0) => "Expired today".to_string(),
+            (Status::Expired, -1) => "Expired 1 day ago".to_string(),
+            (Status::Expired, days) => format!("Expired {} days ago", -days),
+            (Status::Upcoming, 0) => "Expires today".to_string(),
+            (Status::Upcoming, 1) => "Expires tomorrow".to_string(),
+            (Status::Upcoming, days) => format!("Expires in {} days", days),
+        }
+    }
+}
+
+/// a variable whose rotation interval has passed
+#[derive(Serialize)]
+struct RotationStatus<'a> {
+    key: &'a str,
+    interval_days: u32,
+    /// `None` when envio doesn't know when the value last changed
+    due_date: Option<NaiveDate>,
+    /// days since the variable became due, `None` with [`RotationStatus::due_date`]
+    days_overdue: Option<i64>,
+}
+
+#[derive(Serialize)]
+struct CheckResult<'a> {
+    profile: &'a str,
+    expiration: Vec<ExpirationStatus<'a>>,
+    /// variables due for rotation, see `envio rotate-var`
+    rotation: Vec<RotationStatus<'a>>,
+    /// `None` when the project has no schema
+    schema: Option<Vec<Violation>>,
+}
+
+pub fn run(profile_names: &[String]) -> AppResult<()> {
+    let schema = schema::load()?;
+
+    let profiles = profile_names
+        .iter()
+        .map(|name| profile_ops::decrypt_profile(name))
+        .collect::<AppResult<Vec<_>>>()?;
+
+    let current_date = Local::now().date_naive();
+    let mut results = Vec::new();
+
+    for (name, profile) in profile_names.iter().zip(&profiles) {
+        let mut expiration = Vec::new();
+        let mut rotation = Vec::new();
+
+        for env in &profile.envs {
+            if let Some(date) = env.expiration_date {
+                expiration.push(ExpirationStatus {
+                    key: &env.key,
+                    status: if env.is_expired() {
+                        Status::Expired
+                    } else {
+                        Status::Upcoming
+                    },
+                    expiration_date: date,
+                    days_remaining: (date - current_date).num_days(),
+                });
+            }
+
+            if let Some(schedule) = &env.rotation
+                && env.is_rotation_due()
+            {
+                let due_date = env.rotation_due_date();
+                rotation.push(RotationStatus {
+                    key: &env.key,
+                    interval_days: schedule.interval_days,
+                    due_date,
+                    days_overdue: due_date.map(|date| (current_date - date).num_days()),
+                });
+            }
+        }
+
+        results.push(CheckResult {
+            profile: name,
+            expiration,
+            rotation,
+            schema: schema.as_ref().map(|schema| schema.validate(&profile.envs)),
+        });
+    }
+
+    let format = output::format();
+
+    if format.is_machine_readable() {
+        output::print("check", &results)?;
+    } else {
+        for (i, result) in results.iter().enumerate() {
+            if results.len() > 1 {
+                if i > 0 {
+                    println!();
+                }
+                println!("{}", result.profile.bold());
+            }
+
+            print_expiration(&result.expiration, format);
+            print_rotation(&result.rotation, format);
+
+            if let Some(violations) = &result.schema {
+                print_violations(violations, format);
+            }
+        }
+    }
+
+    let failing = results
+        .iter()
+        .flat_map(|result| result.schema.iter().flatten())
+        .filter(|violation| violation.kind != ViolationKind::Unknown)
+        .count();
+
+    if failing > 0 {
+        return Err(AppError::SchemaViolation(failing));
+    }
+
+    Ok(())
+}
+
+fn print_expiration(entries: &[ExpirationStatus], format: OutputFormat) {
+    if entries.is_empty() {
+        if format != OutputFormat::Plain {
+            println!(
+                "{}",
+                "No environment variables with expiration dates found.".bold()
+            );
+        }
+        return;
+    }
+
+    if format == OutputFormat::Plain {
+        for entry in entries {
+            println!(
+                "{}\t{}\t{}",
+                entry.key,
+                if entry.status == Status::Expired {
+                    "expired"
+                } else {
+                    "upcoming"
+                },
+                entry.expiration_date
+            );
+        }
+        return;
+    }
 
     let mut table = Table::new();
     table.set_content_arrangement(ContentArrangement::Dynamic);
@@ -20,62 +181,113 @@ pub fn run(profile_name: &str) -> AppResult<()> {
         Cell::new("Time Description").add_attribute(Attribute::Bold),
     ]);
 
-    let current_date = Local::now().date_naive();
-    let mut entries = Vec::new();
-
-    for env in &profile.envs {
-        if let Some(date) = env.expiration_date {
-            if env.is_expired() {
-                let days_elapsed = (current_date - date).num_days();
-                let time_desc = if days_elapsed == 0 {
-                    "Expired today".to_string()
-                } else if days_elapsed == 1 {
-                    "Expired 1 day ago".to_string()
-                } else {
-                    format!("Expired {} days ago", days_elapsed)
-                };
-                entries.push((&env.key, "Expired", date, time_desc));
-            } else {
-                let days_remaining = (date - current_date).num_days();
-                let time_desc = if days_remaining == 0 {
-                    "Expires today".to_string()
-                } else if days_remaining == 1 {
-                    "Expires tomorrow".to_string()
-                } else {
-                    format!("Expires in {} days", days_remaining)
-                };
-                entries.push((&env.key, "Upcoming", date, time_desc));
-            }
+    for entry in entries {
+        let status_cell = if entry.status == Status::Expired {
+            Cell::new("Expired")
+                .fg(Color::Red)
+                .add_attribute(Attribute::Bold)
+        } else {
+            Cell::new("Upcoming")
+                .fg(Color::Green)
+                .add_attribute(Attribute::Bold)
+        };
+
+        table.add_row(vec![
+            Cell::new(entry.key),
+            status_cell,
+            Cell::new(entry.expiration_date.to_string()),
+            Cell::new(entry.time_description()),
+        ]);
+    }
+
+    println!("{table}");
+}
+
+fn print_rotation(entries: &[RotationStatus], format: OutputFormat) {
+    if format == OutputFormat::Plain {
+        for entry in entries {
+            println!(
+                "{}\trotation-due\t{}",
+                entry.key,
+                entry
+                    .due_date
+                    .map_or("unknown".to_string(), |date| date.to_string())
+            );
         }
+        return;
     }
 
+    // nothing is printed for profiles that are up to date
     if entries.is_empty() {
-        println!(
-            "{}",
-            "No environment variables with expiration dates found.".bold()
-        );
-        return Ok(());
+        return;
     }
 
-    for (key, status, date, time_desc) in entries {
-        let status_cell = if status == "Expired" {
-            Cell::new(status)
-                .fg(Color::Red)
-                .add_attribute(Attribute::Bold)
+    let mut table = Table::new();
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+    table.set_header(vec![
+        Cell::new("Variable").add_attribute(Attribute::Bold),
+        Cell::new("Rotation").add_attribute(Attribute::Bold),
+        Cell::new("Due Date").add_attribute(Attribute::Bold),
+        Cell::new("Time Description").add_attribute(Attribute::Bold),
+    ]);
+
+    for entry in entries {
+        let description = match entry.days_overdue {
+            None => "Never rotated".to_string(),
+            Some(0) => "Due today".to_string(),
+            Some(1) => "Overdue by 1 day".to_string(),
+            Some(days) => format!("Overdue by {} days", days),
+        };
+
+        table.add_row(vec![
+            Cell::new(entry.key),
+            Cell::new(format!("Every {} days", entry.interval_days)),
+            Cell::new(
+                entry
+                    .due_date
+                    .map_or("unknown".to_string(), |date| date.to_string()),
+            ),
+            Cell::new(description)
+                .fg(Color::Yellow)
+                .add_attribute(Attribute::Bold),
+        ]);
+    }
+
+    println!("{table}");
+}
+
+fn print_violations(violations: &[Violation], format: OutputFormat) {
+    if format == OutputFormat::Plain {
+        for violation in violations {
+            println!("{}\t{}", violation.key, violation.message);
+        }
+        return;
+    }
+
+    if violations.is_empty() {
+        println!("{}", "All variables match the schema.".bold());
+        return;
+    }
+
+    let mut table = Table::new();
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+    table.set_header(vec![
+        Cell::new("Variable").add_attribute(Attribute::Bold),
+        Cell::new("Schema").add_attribute(Attribute::Bold),
+    ]);
+
+    for violation in violations {
+        let color = if violation.kind == ViolationKind::Unknown {
+            Color::Yellow
         } else {
-            Cell::new(status)
-                .fg(Color::Green)
-                .add_attribute(Attribute::Bold)
+            Color::Red
         };
 
         table.add_row(vec![
-            Cell::new(key),
-            status_cell,
-            Cell::new(date.to_string()),
-            Cell::new(time_desc),
+            Cell::new(&violation.key),
+            Cell::new(&violation.message).fg(color),
         ]);
     }
 
     println!("{table}");
-    Ok(())
 }
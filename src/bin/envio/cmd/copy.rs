@@ -0,0 +1,141 @@
+use chrono::Local;
+use envio::cipher::{CipherKind, create_cipher};
+use uuid::Uuid;
+use zeroize::Zeroizing;
+
+use crate::{
+    cmd::create,
+    config,
+    error::{AppError, AppResult},
+    profile_ops, success_msg,
+};
+
+/// renames a profile, it keeps its uuid so its keyring key keeps working
+pub fn rename(profile_name: &str, new_name: &str) -> AppResult<()> {
+    config::require_profile(profile_name)?;
+    check_new_name(new_name)?;
+
+    let (store, name) = config::locate_profile(profile_name)?;
+    let (new_store, new_bare_name) = config::locate_profile(new_name)?;
+
+    if new_store.exists(new_bare_name)? {
+        return Err(AppError::ProfileExists(new_name.to_string()));
+    }
+
+    if config::is_global_profile(profile_name) == config::is_global_profile(new_name) {
+        store.rename(name, new_bare_name)?;
+    } else {
+        // between the project and the global store
+        let mut profile = store.load(name)?;
+        profile.metadata.name = new_bare_name.to_string();
+        new_store.save(new_bare_name, &profile)?;
+        store.delete(name)?;
+    }
+
+    if config::get_active_profile()?.as_deref() == Some(profile_name) {
+        config::set_default_profile(Some(new_name))?;
+    }
+
+    success_msg!("Renamed profile '{}' to '{}'", profile_name, new_name);
+    Ok(())
+}
+
+/// copies a profile under a fresh uuid, as is or re-encrypted with `cipher_kind`
+/// or under a new key
+pub fn copy(
+    profile_name: &str,
+    new_name: &str,
+    cipher_kind: Option<&str>,
+    new_key: bool,
+    description: Option<&str>,
+) -> AppResult<()> {
+    config::require_profile(profile_name)?;
+    check_new_name(new_name)?;
+
+    let cipher_kind = cipher_kind
+        .map(|kind| {
+            kind.parse::<CipherKind>()
+                .map_err(|e| AppError::Msg(e.to_string()))
+        })
+        .transpose()?;
+
+    if cipher_kind.is_none() && !new_key {
+        return copy_encrypted(profile_name, new_name, description);
+    }
+
+    let profile = profile_ops::get_profile_cli(profile_name)?;
+    let cipher_kind = cipher_kind.unwrap_or(profile.metadata.cipher_kind);
+
+    let key = create::resolve_key(cipher_kind)?;
+    let cipher = create_cipher(cipher_kind, key.clone())?;
+
+    let copy = profile_ops::create_profile(
+        new_name.to_string(),
+        description
+            .map(str::to_string)
+            .or(profile.metadata.description.clone()),
+        profile.envs.clone(),
+        cipher,
+    )?;
+
+    create::store_key(cipher_kind, key.as_ref(), &copy.metadata.uuid);
+
+    success_msg!("Copied profile '{}' to '{}'", profile_name, new_name);
+    Ok(())
+}
+
+/// copies the encrypted profile without decrypting it, the keyring key is copied
+/// to the new uuid
+fn copy_encrypted(profile_name: &str, new_name: &str, description: Option<&str>) -> AppResult<()> {
+    let (store, name) = config::locate_profile(profile_name)?;
+    let (new_store, new_bare_name) = config::locate_profile(new_name)?;
+
+    if new_store.exists(new_bare_name)? {
+        return Err(AppError::ProfileExists(new_name.to_string()));
+    }
+
+    let mut profile = store.load(name)?;
+    let uuid = profile.metadata.uuid.clone();
+
+    profile.metadata.uuid = Uuid::new_v4().to_string();
+    profile.metadata.name = new_bare_name.to_string();
+    profile.metadata.created_at = Local::now();
+    profile.metadata.updated_at = Local::now();
+    if let Some(description) = description {
+        profile.metadata.description = Some(description.to_string());
+    }
+
+    // the key goes first so a failure doesn't leave a copy that can't be decrypted
+    let key = keyring::Entry::new("envio", &uuid)
+        .and_then(|entry| entry.get_password())
+        .ok()
+        .map(Zeroizing::new);
+    let new_entry = key
+        .map(|key| {
+            keyring::Entry::new("envio", &profile.metadata.uuid)
+                .and_then(|entry| entry.set_password(&key).map(|_| entry))
+                .map_err(|e| AppError::Msg(format!("Failed to store key in keyring: {}", e)))
+        })
+        .transpose()?;
+
+    if let Err(e) = new_store.save(new_bare_name, &profile) {
+        if let Some(entry) = new_entry {
+            let _ = entry.delete_credential();
+        }
+        return Err(e.into());
+    }
+
+    success_msg!("Copied profile '{}' to '{}'", profile_name, new_name);
+    Ok(())
+}
+
+fn check_new_name(new_name: &str) -> AppResult<()> {
+    if config::contains_path_separator(new_name) {
+        return Err(AppError::Msg(format!(
+            "Profile name '{}' cannot contain a path separator",
+            new_name
+        )));
+    }
+
+    Ok(())
+}
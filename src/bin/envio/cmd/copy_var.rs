@@ -0,0 +1,53 @@
+use envio::error::Error;
+
+use crate::{
+    error::{AppError, AppResult},
+    profile_ops, success_msg,
+};
+
+/// copies `keys` with their comments and expiration dates from one profile to
+/// another, removing them from `from` afterwards when `remove` is set
+pub fn run(from: &str, to: &str, keys: &[String], force: bool, remove: bool) -> AppResult<()> {
+    if from == to {
+        return Err(AppError::Msg(
+            "The source and target profile are the same".to_string(),
+        ));
+    }
+
+    let mut source = profile_ops::get_profile_cli(from)?;
+    let mut target = profile_ops::get_profile_cli(to)?;
+
+    for key in keys {
+        let Some(env) = source.envs.get(key) else {
+            return Err(Error::EnvDoesNotExist(key.clone()).into());
+        };
+
+        if !force && target.envs.contains_key(key) {
+            return Err(AppError::Msg(format!(
+                "'{}' already exists in profile '{}', pass --force to overwrite it",
+                key, to
+            )));
+        }
+
+        target.envs.insert(env.clone());
+    }
+
+    // the target is saved first so a failure never loses a variable
+    target.save()?;
+
+    if remove {
+        for key in keys {
+            source.envs.remove(key)?;
+        }
+        source.save()?;
+    }
+
+    success_msg!(
+        "{} {} variable(s) from '{}' to '{}'",
+        if remove { "Moved" } else { "Copied" },
+        keys.len(),
+        from,
+        to
+    );
+    Ok(())
+}
@@ -2,41 +2,82 @@ use std::{io::Read, path::Path};
 
 use chrono::Local;
 use envio::{
-    EnvMap,
-    cipher::{CipherKind, create_cipher, gpg::get_gpg_keys},
+    EnvMap, Sensitivity,
+    cipher::{Cipher, CipherKind, create_cipher, gpg::get_gpg_keys},
+    error::Error,
+    secrets::{self, Confidence},
 };
 use zeroize::Zeroizing;
 
 use crate::{
     config,
     error::{AppError, AppResult},
-    error_msg, profile_ops, prompts, success_msg, utils,
+    error_msg, profile_ops, prompts,
+    settings::{self, KeyringMode, SecretPolicy, Source, settings},
+    success_msg, utils, warning_msg,
 };
 
+pub struct CreateOptions<'a> {
+    pub envs: Option<&'a [String]>,
+    pub envs_file: Option<&'a str>,
+    pub cipher_kind: Option<&'a str>,
+    pub comments: bool,
+    pub expires: bool,
+    /// overrides `create.comments` from the config
+    pub no_comments: bool,
+    /// overrides `create.expires` from the config
+    pub no_expires: bool,
+    /// keys of the variables marked as secret
+    pub secret: &'a [String],
+}
+
 pub fn run(
     profile_name: &str,
     description: Option<&str>,
-    envs: Option<&[String]>,
-    envs_file: Option<&str>,
-    cipher_kind: Option<&str>,
-    add_comments: bool,
-    add_expires: bool,
+    options: &CreateOptions,
 ) -> AppResult<()> {
-    config::get_profile_dir()?;
+    if !config::is_global_profile(profile_name) {
+        config::get_profile_dir()?;
+    }
 
-    let selected_cipher_kind = if let Some(kind) = cipher_kind {
+    let defaults = &settings().create;
+
+    let selected_cipher_kind = if let Some(kind) = options.cipher_kind {
         kind.parse::<CipherKind>()
             .map_err(|e| AppError::Msg(e.to_string()))?
+    } else if let Some(kind) = defaults.cipher
+        // a committed project config must not turn encryption off for everyone
+        && !(kind == CipherKind::NONE && settings::source("create.cipher") == Source::Project)
+    {
+        kind
     } else {
-        prompts::select_cipher_kind_prompt()?
+        prompts::select_cipher_kind_prompt(true)?
     };
 
-    let key = resolve_key(selected_cipher_kind)?;
-    let cipher = create_cipher(selected_cipher_kind, key.clone())?;
+    let mut envs_map = build_envs_map(options.envs, options.envs_file)?;
+
+    for key in options.secret {
+        let Some(env) = envs_map.iter_mut().find(|env| env.key == *key) else {
+            return Err(Error::EnvDoesNotExist(key.clone()).into());
+        };
+        env.sensitivity = Sensitivity::Secret;
+    }
 
-    let mut envs_map = build_envs_map(envs, envs_file)?;
+    annotate_envs(
+        &mut envs_map,
+        options.comments || (defaults.comments && !options.no_comments),
+        options.expires || (defaults.expires && !options.no_expires),
+    )?;
 
-    annotate_envs(&mut envs_map, add_comments, add_expires)?;
+    let (selected_cipher_kind, key, cipher) =
+        match check_unencrypted(profile_name, &envs_map, selected_cipher_kind)? {
+            Some(encryption) => (encryption.kind, encryption.key, encryption.cipher),
+            None => {
+                let key = resolve_key(selected_cipher_kind)?;
+                let cipher = create_cipher(selected_cipher_kind, key.clone())?;
+                (selected_cipher_kind, key, cipher)
+            }
+        };
 
     let profile = profile_ops::create_profile(
         profile_name.to_string(),
@@ -45,31 +86,104 @@ pub fn run(
         cipher,
     )?;
 
-    if matches!(
-        selected_cipher_kind,
-        CipherKind::SYMMETRIC | CipherKind::PASSPHRASE
-    ) {
-        let store = prompts::confirm_prompt(prompts::ConfirmPromptOptions {
+    store_key(selected_cipher_kind, key.as_ref(), &profile.metadata.uuid);
+
+    success_msg!("Profile created");
+    Ok(())
+}
+
+/// the cipher a profile gets when the user agrees to encrypt it, see
+/// [`check_unencrypted`]
+pub struct Encryption {
+    pub kind: CipherKind,
+    pub key: Option<Zeroizing<String>>,
+    pub cipher: Box<dyn Cipher>,
+}
+
+/// warns about, or refuses with `secrets.unencrypted = "refuse"`, values of
+/// `envs` that are secrets when `cipher_kind` doesn't encrypt, and offers to
+/// encrypt the profile instead
+pub fn check_unencrypted(
+    profile_name: &str,
+    envs: &EnvMap,
+    cipher_kind: CipherKind,
+) -> AppResult<Option<Encryption>> {
+    let policy = settings().secrets.unencrypted;
+
+    if cipher_kind != CipherKind::NONE || policy == SecretPolicy::Ignore {
+        return Ok(None);
+    }
+
+    let detections: Vec<_> = secrets::detect(envs)
+        .into_iter()
+        .filter(|detection| detection.confidence == Confidence::High)
+        .collect();
+
+    if detections.is_empty() {
+        return Ok(None);
+    }
+
+    for detection in &detections {
+        warning_msg!(
+            "'{}' looks like a secret ({})",
+            detection.key,
+            detection.kind
+        );
+    }
+
+    let encrypt = prompts::confirm_prompt(prompts::ConfirmPromptOptions {
+        title: format!(
+            "Profile '{}' is not encrypted, do you want to encrypt it?",
+            profile_name
+        ),
+        default: Some(false),
+    })?;
+
+    if !encrypt {
+        if policy == SecretPolicy::Refuse {
+            return Err(AppError::UnencryptedSecrets(detections.len()));
+        }
+
+        warning_msg!("Storing them without encryption");
+        return Ok(None);
+    }
+
+    let kind = prompts::select_cipher_kind_prompt(false)?;
+    let key = resolve_key(kind)?;
+    let cipher = create_cipher(kind, key.clone())?;
+
+    Ok(Some(Encryption { kind, key, cipher }))
+}
+
+/// saves the key of a new profile to the keyring, as configured with `create.keyring`
+pub fn store_key(cipher_kind: CipherKind, key: Option<&Zeroizing<String>>, uuid: &str) {
+    if !matches!(cipher_kind, CipherKind::SYMMETRIC | CipherKind::PASSPHRASE) {
+        return;
+    }
+
+    let store = match settings().create.keyring {
+        KeyringMode::Always => true,
+        KeyringMode::Never => false,
+        KeyringMode::Ask => prompts::confirm_prompt(prompts::ConfirmPromptOptions {
             title: "Do you want to securely store the encryption key in the system keyring?"
                 .to_string(),
             default: Some(true),
         })
-        .unwrap_or(false);
-
-        if store
-            && let Some(ref k) = key
-            && let Ok(entry) = keyring::Entry::new("envio", &profile.metadata.uuid)
-            && let Err(e) = entry.set_password(k)
-        {
-            error_msg!("Failed to store key in keyring: {}", e);
-        }
-    }
+        .unwrap_or(false),
+    };
 
-    success_msg!("Profile created");
-    Ok(())
+    if store
+        && let Some(k) = key
+        && let Ok(entry) = keyring::Entry::new("envio", uuid)
+        && let Err(e) = entry.set_password(k)
+    {
+        error_msg!("Failed to store key in keyring: {}", e);
+    }
 }
 
-fn resolve_key(cipher_kind: CipherKind) -> AppResult<Option<Zeroizing<String>>> {
+/// the key for a new profile encrypted with `cipher_kind`, from `ENVIO_KEY`, a
+/// prompt or generated
+pub fn resolve_key(cipher_kind: CipherKind) -> AppResult<Option<Zeroizing<String>>> {
     match cipher_kind {
         CipherKind::GPG => {
             let available_keys = get_gpg_keys()?;
@@ -95,6 +209,7 @@ fn resolve_key(cipher_kind: CipherKind) -> AppResult<Option<Zeroizing<String>>>
             let selected_label = prompts::select_prompt(prompts::SelectPromptOptions {
                 title: "Select the GPG key you want to use for encryption:".to_string(),
                 options: labels,
+                flag: Some("ENVIO_KEY"),
             })?;
 
             let fingerprint = available_keys
@@ -119,6 +234,7 @@ fn resolve_key(cipher_kind: CipherKind) -> AppResult<Option<Zeroizing<String>>>
                     min_length: Some(8),
                     with_confirmation: true,
                     confirmation_error_message: Some("The keys don't match".to_string()),
+                    flag: Some("ENVIO_KEY"),
                 })?
                 .into()
             };
@@ -186,6 +302,7 @@ fn build_envs_map(envs: Option<&[String]>, envs_file: Option<&str>) -> AppResult
                 let value = prompts::text_prompt(prompts::TextPromptOptions {
                     title: format!("Enter the value for {}:", env),
                     default: None,
+                    flag: Some("the value as KEY=VALUE"),
                 })?;
                 envs_map.insert_from_key_value(env.to_string(), value);
             }
@@ -203,6 +320,7 @@ fn annotate_envs(envs_map: &mut EnvMap, add_comments: bool, add_expires: bool) -
             env.comment = Some(prompts::text_prompt(prompts::TextPromptOptions {
                 title: format!("Enter a comment for '{}':", env.key),
                 default: None,
+                flag: None,
             })?);
         }
 
@@ -210,6 +328,7 @@ fn annotate_envs(envs_map: &mut EnvMap, add_comments: bool, add_expires: bool) -
             env.expiration_date = Some(prompts::date_prompt(prompts::DatePromptOptions {
                 title: format!("Select an expiration date for '{}':", env.key),
                 default: Some(Local::now().date_naive()),
+                flag: None,
             })?);
         }
     }
@@ -4,7 +4,9 @@ use envio::{Env, EnvMap, Profile};
 
 use crate::{
     error::{AppError, AppResult},
-    error_msg, profile_ops, prompts, success_msg,
+    error_msg, profile_ops, prompts,
+    settings::settings,
+    success_msg,
 };
 
 pub fn run(profile_name: &str) -> AppResult<()> {
@@ -66,6 +68,31 @@ fn format_profile_for_editing(profile_name: &str, envs: &EnvMap) -> String {
     output
 }
 
+/// the edited variables with what the editor doesn't show, sensitivity, tags,
+/// labels, rotation, history and timestamps, taken from the variables they replace
+fn carry_over(previous: &EnvMap, edited: EnvMap) -> EnvMap {
+    edited
+        .into_iter()
+        .map(|mut env| {
+            let replaced = previous.get(&env.key);
+
+            if let Some(replaced) = replaced {
+                env.sensitivity = replaced.sensitivity;
+                env.tags = replaced.tags.clone();
+                env.labels = replaced.labels.clone();
+                env.rotation = replaced.rotation.clone();
+                env.history = replaced.history.clone();
+            }
+
+            env.created_at = None;
+            env.updated_at = None;
+            env.touch(replaced);
+            env
+        })
+        .collect::<Vec<_>>()
+        .into()
+}
+
 fn parse_edited_profile(content: &str) -> AppResult<EnvMap> {
     let mut envs = EnvMap::default();
     let mut current_comments = Vec::new();
@@ -149,12 +176,16 @@ fn parse_edited_profile(content: &str) -> AppResult<EnvMap> {
 }
 
 fn open_editor_loop(profile: &mut Profile) -> AppResult<()> {
-    let editor = std::env::var("EDITOR").map_err(|_| {
-        AppError::Msg(
-            "EDITOR environment variable is not set. Please set it to your preferred text editor"
-                .to_string(),
-        )
-    })?;
+    let editor = settings()
+        .editor
+        .clone()
+        .or_else(|| std::env::var("EDITOR").ok())
+        .ok_or_else(|| {
+            AppError::Msg(
+                "No editor configured. Set the EDITOR environment variable or run `envio config set editor <EDITOR>`"
+                    .to_string(),
+            )
+        })?;
 
     let editor_parts: Vec<String> = editor.split_whitespace().map(|s| s.to_string()).collect();
     if editor_parts.is_empty() {
@@ -211,7 +242,7 @@ fn open_editor_loop(profile: &mut Profile) -> AppResult<()> {
         let edited_content = std::fs::read_to_string(&temp_file_path)?;
         match parse_edited_profile(&edited_content) {
             Ok(new_envs) => {
-                profile.envs = new_envs;
+                profile.envs = carry_over(&profile.envs, new_envs);
                 return Ok(());
             }
             Err(e) => {
@@ -222,6 +253,7 @@ fn open_editor_loop(profile: &mut Profile) -> AppResult<()> {
                         "Re-edit the file".to_string(),
                         "Abort (discard changes)".to_string(),
                     ],
+                    flag: None,
                 })?;
 
                 if choice == "Abort (discard changes)" {
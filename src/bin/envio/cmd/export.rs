@@ -6,7 +6,9 @@ use indexmap::IndexMap;
 use crate::{
     config::contains_path_separator,
     error::{AppError, AppResult},
-    profile_ops, prompts, success_msg,
+    profile_ops, prompts,
+    settings::settings,
+    success_msg,
     utils::get_cwd,
 };
 
@@ -14,9 +16,21 @@ pub fn run(
     profile_name: &str,
     output_file_path: Option<&str>,
     keys: Option<&[String]>,
-    format: &str,
+    format: Option<&str>,
+    tags: &[String],
 ) -> AppResult<()> {
-    let profile = profile_ops::get_profile_cli(profile_name)?;
+    let defaults = &settings().export;
+    let format = format
+        .or(defaults.format.map(|format| format.as_str()))
+        .unwrap_or("dotenv");
+
+    let mut profile = profile_ops::get_profile_cli(profile_name)?;
+
+    if !tags.is_empty() {
+        profile
+            .envs
+            .retain(|env| tags.iter().any(|tag| env.has_tag(tag)));
+    }
 
     let envs_selected = resolve_key_selection(&profile, keys)?;
 
@@ -26,7 +40,9 @@ pub fn run(
         "shell" => format!("{}.sh", profile_name),
         _ => ".env".to_string(),
     };
-    let output = output_file_path.unwrap_or(&default_file);
+    let output = output_file_path
+        .or(defaults.output.as_deref())
+        .unwrap_or(&default_file);
 
     export_envs(&profile, output, &envs_selected, format)?;
     success_msg!("Exported envs to {}", output);
@@ -0,0 +1,239 @@
+use colored::Colorize;
+use comfy_table::{Attribute, Cell, ContentArrangement, Table};
+use regex::{Regex, RegexBuilder};
+use serde::Serialize;
+
+use crate::{
+    config,
+    error::{AppError, AppResult},
+    output::{self, OutputFormat},
+    profile_ops, warning_msg,
+};
+
+#[derive(Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Field {
+    Key,
+    Value,
+    Comment,
+}
+
+impl Field {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Key => "key",
+            Self::Value => "value",
+            Self::Comment => "comment",
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Match {
+    profile: String,
+    key: String,
+    field: Field,
+    /// character ranges of the matches, end exclusive
+    ranges: Vec<(usize, usize)>,
+    /// the matched text with values masked, only the match positions are shown
+    preview: String,
+}
+
+#[derive(Serialize)]
+struct Skipped {
+    profile: String,
+    reason: String,
+}
+
+#[derive(Serialize)]
+struct GrepResult {
+    matches: Vec<Match>,
+    /// profiles that could not be unlocked and were not searched
+    skipped: Vec<Skipped>,
+}
+
+pub struct GrepOptions {
+    pub keys: bool,
+    pub values: bool,
+    pub comments: bool,
+    pub regex: bool,
+    pub ignore_case: bool,
+}
+
+/// searches every project and global profile, or only the global ones with
+/// `--global`, for `pattern`
+pub fn run(pattern: &str, options: &GrepOptions, global_only: bool) -> AppResult<()> {
+    let regex = RegexBuilder::new(&if options.regex {
+        pattern.to_string()
+    } else {
+        regex::escape(pattern)
+    })
+    .case_insensitive(options.ignore_case)
+    .build()
+    .map_err(|e| AppError::Msg(format!("Invalid pattern: {}", e)))?;
+
+    // without a field flag everything is searched
+    let all = !(options.keys || options.values || options.comments);
+    let fields: Vec<Field> = [
+        (Field::Key, options.keys),
+        (Field::Value, options.values),
+        (Field::Comment, options.comments),
+    ]
+    .into_iter()
+    .filter(|(_, enabled)| all || *enabled)
+    .map(|(field, _)| field)
+    .collect();
+
+    let mut result = GrepResult {
+        matches: Vec::new(),
+        skipped: Vec::new(),
+    };
+
+    for profile_name in profile_names(global_only)? {
+        let profile = match profile_ops::decrypt_profile(&profile_name) {
+            Ok(profile) => profile,
+            // an interrupted prompt stops the whole search
+            Err(e @ AppError::Prompt(_)) => return Err(e),
+            Err(e) => {
+                result.skipped.push(Skipped {
+                    profile: profile_name,
+                    reason: e.to_string(),
+                });
+                continue;
+            }
+        };
+
+        for env in &profile.envs {
+            for &field in &fields {
+                let text = match field {
+                    Field::Key => &env.key,
+                    Field::Value => &env.value,
+                    Field::Comment => match &env.comment {
+                        Some(comment) => comment,
+                        None => continue,
+                    },
+                };
+
+                let ranges = find(&regex, text);
+                if ranges.is_empty() {
+                    continue;
+                }
+
+                result.matches.push(Match {
+                    profile: profile_name.clone(),
+                    key: env.key.clone(),
+                    field,
+                    preview: if field == Field::Value {
+                        mask(text, &ranges)
+                    } else {
+                        text.clone()
+                    },
+                    ranges,
+                });
+            }
+        }
+    }
+
+    print(&result)
+}
+
+fn profile_names(global_only: bool) -> AppResult<Vec<String>> {
+    if global_only {
+        return Ok(config::global_store()?.list()?);
+    }
+
+    // outside of a project only the global store is searched
+    let mut names = if config::get_profile_dir().is_ok() {
+        config::collect_profile_names()?
+    } else {
+        Vec::new()
+    };
+
+    names.extend(
+        config::global_store()?
+            .list()?
+            .into_iter()
+            .map(|name| format!("{}{}", config::GLOBAL_PREFIX, name)),
+    );
+
+    Ok(names)
+}
+
+/// character ranges of every match of `regex` in `text`
+fn find(regex: &Regex, text: &str) -> Vec<(usize, usize)> {
+    regex
+        .find_iter(text)
+        .filter(|m| !m.is_empty())
+        .map(|m| {
+            let start = text[..m.start()].chars().count();
+            (start, start + m.as_str().chars().count())
+        })
+        .collect()
+}
+
+/// `*` for every character of `value`, `^` for the matched ones
+fn mask(value: &str, ranges: &[(usize, usize)]) -> String {
+    (0..value.chars().count())
+        .map(|i| {
+            if ranges.iter().any(|&(start, end)| (start..end).contains(&i)) {
+                '^'
+            } else {
+                '*'
+            }
+        })
+        .collect()
+}
+
+fn print(result: &GrepResult) -> AppResult<()> {
+    let format = output::format();
+
+    if format.is_machine_readable() {
+        return output::print("grep", result);
+    }
+
+    if format == OutputFormat::Plain {
+        for m in &result.matches {
+            println!(
+                "{}\t{}\t{}\t{}",
+                m.profile,
+                m.key,
+                m.field.as_str(),
+                m.preview
+            );
+        }
+    } else if result.matches.is_empty() {
+        println!("{}", "No matches found.".bold());
+    } else {
+        let mut table = Table::new();
+        table.set_content_arrangement(ContentArrangement::Dynamic);
+        table.set_header(vec![
+            Cell::new("Profile").add_attribute(Attribute::Bold),
+            Cell::new("Variable").add_attribute(Attribute::Bold),
+            Cell::new("Found In").add_attribute(Attribute::Bold),
+            Cell::new("Match").add_attribute(Attribute::Bold),
+        ]);
+
+        for m in &result.matches {
+            table.add_row(vec![
+                Cell::new(&m.profile),
+                Cell::new(&m.key),
+                Cell::new(m.field.as_str()),
+                Cell::new(&m.preview),
+            ]);
+        }
+
+        println!("{table}");
+    }
+
+    if !result.skipped.is_empty() {
+        warning_msg!(
+            "{} profile(s) could not be unlocked and were not searched:",
+            result.skipped.len()
+        );
+        for skipped in &result.skipped {
+            eprintln!("  {}: {}", skipped.profile, skipped.reason);
+        }
+    }
+
+    Ok(())
+}
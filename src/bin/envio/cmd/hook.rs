@@ -0,0 +1,272 @@
+use std::{borrow::Cow, collections::HashMap, path::PathBuf};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use envio::{
+    environment::{build_environment, process_vars},
+    get_profile_from,
+    lint::is_posix_name,
+};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{AppError, AppResult},
+    error_msg, hook, log_msg, profile_ops, warning_msg,
+};
+
+/// exported by the hook to remember what it loaded and what it has to restore
+const STATE_VAR: &str = "ENVIO_HOOK_STATE";
+
+const BASH_HOOK: &str = r#"_envio_hook() {
+  local previous_exit_status=$?
+  trap -- '' SIGINT
+  eval "$({envio} hook-env bash)"
+  trap - SIGINT
+  return $previous_exit_status
+}
+if [[ ";${PROMPT_COMMAND[*]:-};" != *";_envio_hook;"* ]]; then
+  if [[ "$(declare -p PROMPT_COMMAND 2>&1)" == "declare -a"* ]]; then
+    PROMPT_COMMAND=(_envio_hook "${PROMPT_COMMAND[@]}")
+  else
+    PROMPT_COMMAND="_envio_hook${PROMPT_COMMAND:+;$PROMPT_COMMAND}"
+  fi
+fi"#;
+
+const ZSH_HOOK: &str = r#"_envio_hook() {
+  trap -- '' SIGINT
+  eval "$({envio} hook-env zsh)"
+  trap - SIGINT
+}
+typeset -ag precmd_functions
+if (( ! ${precmd_functions[(I)_envio_hook]} )); then
+  precmd_functions=(_envio_hook $precmd_functions)
+fi
+typeset -ag chpwd_functions
+if (( ! ${chpwd_functions[(I)_envio_hook]} )); then
+  chpwd_functions=(_envio_hook $chpwd_functions)
+fi"#;
+
+const FISH_HOOK: &str = r#"function __envio_hook --on-event fish_prompt --on-variable PWD
+  {envio} hook-env fish | source
+end"#;
+
+#[derive(Clone, Copy)]
+enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+impl Shell {
+    fn parse(shell: &str) -> AppResult<Self> {
+        match shell {
+            "bash" => Ok(Self::Bash),
+            "zsh" => Ok(Self::Zsh),
+            "fish" => Ok(Self::Fish),
+            _ => Err(AppError::UnsupportedShell(shell.to_string())),
+        }
+    }
+
+    fn quote(self, value: &str) -> String {
+        match self {
+            Self::Bash | Self::Zsh => shell_escape::unix::escape(Cow::Borrowed(value)).to_string(),
+            Self::Fish => format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'")),
+        }
+    }
+
+    fn set(self, key: &str, value: &str) -> String {
+        match self {
+            Self::Bash | Self::Zsh => format!("export {}={};", key, self.quote(value)),
+            Self::Fish => format!("set -gx {} {};", key, self.quote(value)),
+        }
+    }
+
+    fn unset(self, key: &str) -> String {
+        match self {
+            Self::Bash | Self::Zsh => format!("unset {};", key),
+            Self::Fish => format!("set -e {};", key),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct HookState {
+    dir: PathBuf,
+    hash: String,
+    allowed: bool,
+    profile_name: Option<String>,
+    /// values the loaded variables had before, `None` if they were unset
+    previous: IndexMap<String, Option<String>>,
+}
+
+impl HookState {
+    fn from_env() -> Option<Self> {
+        let encoded = std::env::var(STATE_VAR).ok()?;
+        serde_json::from_slice(&STANDARD.decode(encoded).ok()?).ok()
+    }
+
+    fn encode(&self) -> AppResult<String> {
+        Ok(STANDARD.encode(serde_json::to_vec(self)?))
+    }
+}
+
+pub fn run(shell: &str) -> AppResult<()> {
+    let shell = Shell::parse(shell)?;
+    let exe = std::env::current_exe()?;
+    let exe = shell.quote(&exe.to_string_lossy());
+
+    let hook = match shell {
+        Shell::Bash => BASH_HOOK,
+        Shell::Zsh => ZSH_HOOK,
+        Shell::Fish => FISH_HOOK,
+    };
+
+    println!("{}", hook.replace("{envio}", &exe));
+    Ok(())
+}
+
+/// prints the commands that bring the shell in line with the current directory
+pub fn run_env(shell: &str) -> AppResult<()> {
+    let shell = Shell::parse(shell)?;
+
+    let state = HookState::from_env();
+    let project = std::env::current_dir()
+        .and_then(|dir| dir.canonicalize())
+        .ok()
+        .and_then(|dir| hook::find_project(&dir));
+    let hash = project
+        .as_deref()
+        .map(|dir| hook::content_hash(dir).unwrap_or_default());
+    let allowed = match (&project, &hash) {
+        (Some(dir), Some(hash)) => hook::is_allowed(dir, hash),
+        _ => false,
+    };
+
+    let unchanged = match (&state, &project) {
+        (Some(state), Some(dir)) => {
+            state.dir == *dir && Some(&state.hash) == hash.as_ref() && state.allowed == allowed
+        }
+        (None, None) => true,
+        _ => false,
+    };
+
+    if unchanged {
+        return Ok(());
+    }
+
+    let mut environment: HashMap<String, String> = process_vars().collect();
+    let mut changes: IndexMap<String, Option<String>> = IndexMap::new();
+
+    if let Some(state) = &state {
+        for (key, value) in &state.previous {
+            apply(&mut environment, &mut changes, key, value.clone());
+        }
+
+        if let Some(name) = &state.profile_name {
+            // success_msg! prints to stdout, which the shell evaluates
+            log_msg!(eprintln, Success, green, "Unloaded profile `{}`", name);
+        }
+    }
+
+    let mut new_state = None;
+
+    if let (Some(dir), Some(hash)) = (project, hash) {
+        let mut state = HookState {
+            dir,
+            hash,
+            allowed,
+            profile_name: None,
+            previous: IndexMap::new(),
+        };
+
+        match load(&state.dir, &state.hash, allowed) {
+            Ok(Some((name, envs))) => {
+                for (key, value) in envs {
+                    if !is_posix_name(&key) {
+                        warning_msg!("Skipping `{}`, it is not a valid shell variable name", key);
+                        continue;
+                    }
+
+                    state
+                        .previous
+                        .insert(key.clone(), environment.get(&key).cloned());
+                    apply(&mut environment, &mut changes, &key, Some(value));
+                }
+
+                log_msg!(
+                    eprintln,
+                    Success,
+                    green,
+                    "Loaded profile `{}` ({} variables)",
+                    name,
+                    state.previous.len()
+                );
+                state.profile_name = Some(name);
+            }
+            Ok(None) => {
+                warning_msg!(
+                    "{} is blocked, run `envio allow` to load its active profile",
+                    state.dir.display()
+                );
+            }
+            Err(e) => error_msg!(e),
+        }
+
+        new_state = Some(state);
+    }
+
+    let mut output: Vec<String> = changes
+        .iter()
+        .map(|(key, value)| match value {
+            Some(value) => shell.set(key, value),
+            None => shell.unset(key),
+        })
+        .collect();
+
+    output.push(match new_state {
+        Some(state) => shell.set(STATE_VAR, &state.encode()?),
+        None => shell.unset(STATE_VAR),
+    });
+
+    println!("{}", output.join("\n"));
+    Ok(())
+}
+
+fn apply(
+    environment: &mut HashMap<String, String>,
+    changes: &mut IndexMap<String, Option<String>>,
+    key: &str,
+    value: Option<String>,
+) {
+    match &value {
+        Some(value) => environment.insert(key.to_string(), value.clone()),
+        None => environment.remove(key),
+    };
+    changes.insert(key.to_string(), value);
+}
+
+/// loads the active profile of `dir`, `None` if it hasn't been allowed
+fn load(
+    dir: &std::path::Path,
+    hash: &str,
+    allowed: bool,
+) -> AppResult<Option<(String, IndexMap<String, String>)>> {
+    if hash.is_empty() {
+        // hashing failed, hash again to surface the reason
+        hook::content_hash(dir)?;
+    }
+
+    if !allowed {
+        return Ok(None);
+    }
+
+    let name = hook::active_profile(dir)?;
+    let profile = get_profile_from(hook::store(dir)?, &name, Some(profile_ops::resolve_key))?;
+
+    for env in profile.expired_envs() {
+        warning_msg!("environment variable '{}' has expired", env.key);
+    }
+
+    let envs = build_environment(std::iter::empty(), [&profile.envs], &Default::default());
+    Ok(Some((name, envs)))
+}
@@ -1,13 +1,17 @@
 use std::path::Path;
 
-use envio::{profile::SerializedProfile, utils as envio_utils};
+use envio::{
+    cipher::{CipherKind, create_cipher},
+    profile::SerializedProfile,
+};
 use url::Url;
 
 use crate::{
-    config::{self, build_profile_path},
+    cmd::create,
+    config,
     error::{AppError, AppResult},
     success_msg,
-    utils::download_file,
+    utils::download,
 };
 
 pub fn run(source: &str, profile_name: Option<&str>) -> AppResult<()> {
@@ -19,43 +23,65 @@ pub fn run(source: &str, profile_name: Option<&str>) -> AppResult<()> {
             .to_string()
     });
 
-    if Url::parse(source).is_ok() {
-        download_profile(source, &profile_name)?;
+    let (store, name) = config::locate_profile(&profile_name)?;
+
+    if store.exists(name)? {
+        return Err(AppError::ProfileExists(profile_name));
+    }
+
+    let content = if Url::parse(source).is_ok() {
+        download_profile(source)?
     } else if Path::new(source).exists() {
-        import_profile(source, &profile_name)?;
+        read_profile(source)?
     } else {
         return Err(AppError::Msg(
             "Source must be a valid file path or URL".to_string(),
         ));
-    }
+    };
 
-    success_msg!("Imported profile");
+    let mut serialized: SerializedProfile = serde_json::from_slice(&content)
+        .map_err(|e| envio::error::Error::Corrupted(e.to_string()))?;
 
-    // patch the profile name stored in the metadata to match the target name
-    let location = config::build_profile_path(&profile_name)?;
-    let mut serialized: SerializedProfile = envio_utils::get_serialized_profile(&location)?;
-    serialized.metadata.name = profile_name;
-    envio_utils::save_serialized_profile(&location, serialized)?;
+    // the name stored in the metadata has to match the target name
+    serialized.metadata.name = name.to_string();
 
-    Ok(())
-}
+    let mut encryption = None;
+
+    if serialized.metadata.cipher_kind == CipherKind::NONE {
+        let envs = create_cipher(CipherKind::NONE, None)?.decrypt(&serialized.content)?;
+        encryption = create::check_unencrypted(&profile_name, &envs, CipherKind::NONE)?;
+
+        if let Some(encryption) = &mut encryption {
+            serialized.content = encryption.cipher.encrypt(&envs)?;
+            serialized.metadata.cipher_kind = encryption.kind;
+            serialized.metadata.cipher_metadata = encryption.cipher.export_metadata();
+        }
+    }
 
-fn download_profile(url: &str, profile_name: &str) -> AppResult<()> {
-    let location = build_profile_path(profile_name)?;
+    store.save(name, &serialized)?;
 
-    if location.exists() {
-        return Err(AppError::ProfileExists(profile_name.to_owned()));
+    if let Some(encryption) = encryption {
+        create::store_key(
+            encryption.kind,
+            encryption.key.as_ref(),
+            &serialized.metadata.uuid,
+        );
     }
 
+    success_msg!("Imported profile");
+
+    Ok(())
+}
+
+fn download_profile(url: &str) -> AppResult<Vec<u8>> {
     let runtime = tokio::runtime::Builder::new_current_thread()
         .enable_all()
         .build()?;
 
-    runtime.block_on(download_file(url, &location))?;
-    Ok(())
+    runtime.block_on(download(url))
 }
 
-fn import_profile(file_path: &str, profile_name: &str) -> AppResult<()> {
+fn read_profile(file_path: &str) -> AppResult<Vec<u8>> {
     let path = Path::new(file_path);
 
     if !path.exists() {
@@ -65,13 +91,5 @@ fn import_profile(file_path: &str, profile_name: &str) -> AppResult<()> {
         )));
     }
 
-    let contents = std::fs::read_to_string(path)?;
-    let location = build_profile_path(profile_name)?;
-
-    if location.exists() {
-        return Err(AppError::ProfileExists(profile_name.to_owned()));
-    }
-
-    std::fs::write(location, contents)?;
-    Ok(())
+    Ok(std::fs::read(path)?)
 }
@@ -1,10 +1,11 @@
 use crate::{
+    config,
     error::{AppError, AppResult},
-    success_msg, utils,
+    success_msg,
 };
 
 pub fn run() -> AppResult<()> {
-    let envio_dir = utils::get_cwd().join(".envio");
+    let envio_dir = config::init_dir();
 
     if envio_dir.exists() {
         return Err(AppError::Msg(
@@ -12,7 +13,7 @@ pub fn run() -> AppResult<()> {
         ));
     }
 
-    std::fs::create_dir(&envio_dir)?;
+    std::fs::create_dir_all(&envio_dir)?;
     std::fs::create_dir(envio_dir.join("profiles"))?;
     success_msg!("Initialized envio in the current project directory");
     Ok(())
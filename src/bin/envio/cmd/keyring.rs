@@ -1,4 +1,4 @@
-use envio::{cipher::CipherKind, profile::SerializedProfile, utils as envio_utils};
+use envio::cipher::CipherKind;
 use zeroize::Zeroizing;
 
 use crate::{
@@ -8,22 +8,27 @@ use crate::{
 };
 
 pub fn add_key(profile_name: &str) -> AppResult<()> {
-    let location = config::get_profile_path(profile_name)?;
-    let serialized: SerializedProfile = envio_utils::get_serialized_profile(&location)?;
+    let metadata = config::get_profile_metadata(profile_name)?;
 
     if !matches!(
-        serialized.metadata.cipher_kind,
+        metadata.cipher_kind,
         CipherKind::SYMMETRIC | CipherKind::PASSPHRASE
     ) {
         return Err(AppError::Msg(format!(
             "The cipher type '{}' does not support storing keys in the keyring",
-            serialized.metadata.cipher_kind
+            metadata.cipher_kind
         )));
     }
 
     let key = prompt_key_for_profile(profile_name)?;
 
-    let entry = keyring::Entry::new("envio", &serialized.metadata.uuid)
+    // storing a wrong key would break every later command
+    config::open_profile(
+        profile_name,
+        Some(|_: &envio::ProfileMetadata| Ok::<_, envio::error::Error>(key.clone())),
+    )?;
+
+    let entry = keyring::Entry::new("envio", &metadata.uuid)
         .map_err(|e| AppError::Msg(format!("Failed to access keyring: {}", e)))?;
 
     entry
@@ -38,10 +43,9 @@ pub fn add_key(profile_name: &str) -> AppResult<()> {
 }
 
 pub fn remove_key(profile_name: &str) -> AppResult<()> {
-    let location = config::get_profile_path(profile_name)?;
-    let serialized: SerializedProfile = envio_utils::get_serialized_profile(&location)?;
+    let metadata = config::get_profile_metadata(profile_name)?;
 
-    let entry = keyring::Entry::new("envio", &serialized.metadata.uuid)
+    let entry = keyring::Entry::new("envio", &metadata.uuid)
         .map_err(|e| AppError::Msg(format!("Failed to access keyring: {}", e)))?;
 
     match entry.delete_credential() {
@@ -73,7 +77,7 @@ fn prompt_key_for_profile(profile_name: &str) -> AppResult<Zeroizing<String>> {
         min_length: None,
         with_confirmation: false,
         confirmation_error_message: None,
+        flag: None,
     })
     .map(Zeroizing::new)
-    .map_err(|e| AppError::Msg(e.to_string()))
 }
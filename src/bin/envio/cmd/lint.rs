@@ -0,0 +1,211 @@
+use std::{collections::HashSet, str::FromStr};
+
+use colored::Colorize;
+use comfy_table::{Attribute, Cell, Color, ContentArrangement, Table};
+use envio::{
+    EnvMap,
+    cipher::CipherKind,
+    lint::{self, Issue, LintOptions, Rule, Severity},
+};
+use serde::Serialize;
+use strum::IntoEnumIterator;
+
+use crate::{
+    config,
+    error::{AppError, AppResult},
+    output::{self, OutputFormat},
+    profile_ops,
+    settings::{SecretPolicy, settings},
+    success_msg,
+};
+
+#[derive(Serialize)]
+struct ProfileIssue<'a> {
+    profile: &'a str,
+    #[serde(flatten)]
+    issue: &'a Issue,
+}
+
+pub fn run(profile_names: &[String], fix: bool, disable: &[String], strict: bool) -> AppResult<()> {
+    let defaults = &settings().lint;
+    let strict = strict || defaults.strict;
+
+    let mut options = LintOptions {
+        disabled: HashSet::new(),
+        max_value_size: defaults.max_value_size,
+    };
+    for name in defaults.disable.iter().chain(disable) {
+        options.disabled.insert(parse_rule(name)?);
+    }
+
+    let profile_names = if profile_names.is_empty() {
+        let mut names = config::collect_profile_names()?;
+        names.sort();
+        names
+    } else {
+        profile_names.to_vec()
+    };
+
+    let mut results = Vec::new();
+
+    for name in &profile_names {
+        let mut profile = profile_ops::decrypt_profile(name)?;
+        let mut issues = lint::lint(&profile.envs, &options);
+
+        if fix {
+            let applied = lint::apply_fixes(&mut profile.envs, &issues)?;
+
+            if applied > 0 {
+                profile.save()?;
+                success_msg!("Fixed {} problem(s) in `{}`", applied, name);
+                issues = lint::lint(&profile.envs, &options);
+            }
+        }
+
+        if profile.metadata.cipher_kind == CipherKind::NONE {
+            issues.extend(unencrypted_issues(&profile.envs, &options));
+        }
+
+        results.push((name.as_str(), issues));
+    }
+
+    let failing = results
+        .iter()
+        .flat_map(|(_, issues)| issues)
+        .filter(|issue| strict || issue.severity == Severity::Error)
+        .count();
+
+    print_results(&results)?;
+
+    if failing > 0 {
+        return Err(AppError::LintFailed(failing));
+    }
+
+    Ok(())
+}
+
+/// secrets in an unencrypted profile, errors with `secrets.unencrypted = "refuse"`
+fn unencrypted_issues(envs: &EnvMap, options: &LintOptions) -> Vec<Issue> {
+    let policy = settings().secrets.unencrypted;
+
+    if policy == SecretPolicy::Ignore {
+        return Vec::new();
+    }
+
+    let mut issues = lint::lint_unencrypted(envs, options);
+
+    if policy == SecretPolicy::Refuse {
+        for issue in &mut issues {
+            issue.severity = Severity::Error;
+        }
+    }
+
+    issues
+}
+
+pub fn list_rules() -> AppResult<()> {
+    let mut table = Table::new();
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+    table.set_header(vec![
+        Cell::new("Rule").add_attribute(Attribute::Bold),
+        Cell::new("Description").add_attribute(Attribute::Bold),
+    ]);
+
+    for rule in Rule::iter() {
+        table.add_row(vec![rule.to_string(), rule.description().to_string()]);
+    }
+
+    println!("{table}");
+    Ok(())
+}
+
+fn parse_rule(name: &str) -> AppResult<Rule> {
+    Rule::from_str(name).map_err(|_| {
+        AppError::Msg(format!(
+            "Unknown lint rule `{}`, run `envio lint --list-rules` to see all rules",
+            name
+        ))
+    })
+}
+
+fn print_results(results: &[(&str, Vec<Issue>)]) -> AppResult<()> {
+    let format = output::format();
+
+    if format.is_machine_readable() {
+        let issues: Vec<ProfileIssue> = results
+            .iter()
+            .flat_map(|(profile, issues)| {
+                issues.iter().map(|issue| ProfileIssue { profile, issue })
+            })
+            .collect();
+
+        return output::print("lint", issues);
+    }
+
+    if results.iter().all(|(_, issues)| issues.is_empty()) {
+        if format != OutputFormat::Plain {
+            println!("{}", "No problems found".bold());
+        }
+        return Ok(());
+    }
+
+    if format == OutputFormat::Plain {
+        for (profile, issues) in results {
+            for issue in issues {
+                println!(
+                    "{}:{}: {}[{}] {}",
+                    profile,
+                    issue.key,
+                    severity_name(issue.severity),
+                    issue.rule,
+                    issue.message
+                );
+            }
+        }
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+    table.set_header(vec![
+        Cell::new("Profile").add_attribute(Attribute::Bold),
+        Cell::new("Variable").add_attribute(Attribute::Bold),
+        Cell::new("Severity").add_attribute(Attribute::Bold),
+        Cell::new("Rule").add_attribute(Attribute::Bold),
+        Cell::new("Problem").add_attribute(Attribute::Bold),
+    ]);
+
+    for (profile, issues) in results {
+        for issue in issues {
+            let color = match issue.severity {
+                Severity::Error => Color::Red,
+                Severity::Warning => Color::Yellow,
+            };
+
+            let mut problem = issue.message.clone();
+            if issue.fix.is_some() {
+                problem.push_str(" (fixable)");
+            }
+
+            table.add_row(vec![
+                Cell::new(profile),
+                Cell::new(&issue.key),
+                Cell::new(severity_name(issue.severity))
+                    .fg(color)
+                    .add_attribute(Attribute::Bold),
+                Cell::new(issue.rule),
+                Cell::new(problem),
+            ]);
+        }
+    }
+
+    println!("{table}");
+    Ok(())
+}
+
+fn severity_name(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+    }
+}
@@ -1,29 +1,103 @@
 use colored::Colorize;
 use comfy_table::{Attribute, Cell, ContentArrangement, Table};
+use envio::ProfileMetadata;
+use serde::Serialize;
 
-use crate::{config, error::AppResult};
+use crate::{
+    config,
+    error::AppResult,
+    output::{self, OutputFormat},
+};
 
-pub fn run(no_pretty_print: bool) -> AppResult<()> {
-    let profiles = config::collect_profile_names()?;
+#[derive(Serialize)]
+struct ProfileEntry {
+    /// the name to pass to other commands, `~` prefixed for global profiles
+    name: String,
+    global: bool,
+    metadata: ProfileMetadata,
+}
+
+pub fn run(no_pretty_print: bool, global_only: bool) -> AppResult<()> {
+    // outside of a project only the global store is listed
+    let project_profiles = if global_only {
+        None
+    } else {
+        config::get_profile_dir()
+            .ok()
+            .map(|_| config::collect_profile_metadata())
+            .transpose()?
+    };
+
+    let global_profiles: Vec<ProfileMetadata> = config::collect_global_profile_metadata()?
+        .into_iter()
+        .map(|mut metadata| {
+            metadata.name = format!("{}{}", config::GLOBAL_PREFIX, metadata.name);
+            metadata
+        })
+        .collect();
+
+    let sections = [
+        ("Project profiles", project_profiles.unwrap_or_default()),
+        ("Global profiles", global_profiles),
+    ];
+
+    let format = output::format_or_plain(no_pretty_print);
+
+    if format.is_machine_readable() {
+        let mut entries = Vec::new();
+
+        for (global, profiles) in [(false, &sections[0].1), (true, &sections[1].1)] {
+            for metadata in profiles {
+                entries.push(ProfileEntry {
+                    name: metadata.name.clone(),
+                    global,
+                    metadata: metadata.clone(),
+                });
+            }
+        }
+
+        return output::print("profile_list", entries);
+    }
+
+    if sections.iter().all(|(_, profiles)| profiles.is_empty()) {
+        println!("{}", "No profiles found".bold());
+        return Ok(());
+    }
 
-    if no_pretty_print {
+    let mut first = true;
+    for (title, profiles) in &sections {
         if profiles.is_empty() {
-            println!("{}", "No profiles found".bold());
-            return Ok(());
+            continue;
         }
 
-        for profile in &profiles {
-            println!(
-                "{} - {}",
-                profile,
-                config::get_profile_metadata(profile)?
-                    .description
-                    .unwrap_or_default()
-            );
+        if format == OutputFormat::Plain {
+            print_plain(profiles);
+            continue;
         }
-        return Ok(());
+
+        if !first {
+            println!();
+        }
+        first = false;
+
+        println!("{}", title.bold());
+        print_table(profiles);
+    }
+
+    Ok(())
+}
+
+fn print_plain(profiles: &[ProfileMetadata]) {
+    for metadata in profiles {
+        println!(
+            "{} - {}",
+            metadata.name,
+            metadata.description.as_deref().unwrap_or_default()
+        );
     }
+}
 
+fn print_table(profiles: &[ProfileMetadata]) {
     let mut table = Table::new();
     table.set_content_arrangement(ContentArrangement::Dynamic);
     table.set_header(vec![
@@ -34,11 +108,10 @@ pub fn run(no_pretty_print: bool) -> AppResult<()> {
         Cell::new("Updated At").add_attribute(Attribute::Bold),
     ]);
 
-    for profile in &profiles {
-        let metadata = config::get_profile_metadata(profile)?;
+    for metadata in profiles {
         table.add_row(vec![
-            profile,
-            &metadata.description.unwrap_or_default(),
+            &metadata.name,
+            metadata.description.as_deref().unwrap_or_default(),
             metadata.cipher_kind.as_ref(),
             &metadata.created_at.format("%Y-%m-%d %H:%M:%S").to_string(),
             &metadata.updated_at.format("%Y-%m-%d %H:%M:%S").to_string(),
@@ -46,5 +119,4 @@ pub fn run(no_pretty_print: bool) -> AppResult<()> {
     }
 
     println!("{table}");
-    Ok(())
 }
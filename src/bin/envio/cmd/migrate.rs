@@ -0,0 +1,77 @@
+use crate::{
+    cmd::settings as settings_cmd,
+    config,
+    error::{AppError, AppResult},
+    settings::{self, StorageBackend},
+    success_msg, warning_msg,
+};
+
+/// moves every profile of the project, or the global store with `--global`, to
+/// `backend` and switches `storage.backend` over to it
+pub fn run(backend: &str, global: bool) -> AppResult<()> {
+    let envio_dir = if global {
+        config::get_global_dir()
+    } else {
+        config::get_envio_dir()?
+    };
+
+    let target = StorageBackend::parse(backend)?;
+    let current = settings::storage_backend(&envio_dir)?;
+
+    if target == current {
+        return Err(AppError::Msg(format!(
+            "Profiles are already stored with the {} backend",
+            target.as_str()
+        )));
+    }
+
+    let source = config::open_backend(current, &envio_dir)?;
+    let destination = config::open_backend(target, &envio_dir)?;
+
+    let names = source.list()?;
+
+    // nothing is moved unless everything can be
+    if let Some(name) = names
+        .iter()
+        .find(|name| destination.exists(name).unwrap_or(false))
+    {
+        return Err(AppError::ProfileExists(name.clone()));
+    }
+
+    for name in &names {
+        destination.save(name, &source.load(name)?)?;
+    }
+
+    // the config points at the copies before anything is deleted, so a failure
+    // below leaves a stale copy behind instead of profiles that look lost
+    settings_cmd::set("storage.backend", target.as_str(), global)?;
+
+    let leftovers: Vec<String> = names
+        .iter()
+        .filter_map(|name| {
+            source
+                .delete(name)
+                .err()
+                .map(|e| format!("{}: {}", name, e))
+        })
+        .collect();
+
+    success_msg!(
+        "Moved {} profile(s) to the {} backend",
+        names.len(),
+        target.as_str()
+    );
+
+    if !leftovers.is_empty() {
+        warning_msg!(
+            "{} profile(s) could not be removed from the {} backend and are still there:",
+            leftovers.len(),
+            current.as_str()
+        );
+        for leftover in leftovers {
+            eprintln!("  {}", leftover);
+        }
+    }
+
+    Ok(())
+}
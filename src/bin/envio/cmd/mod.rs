@@ -1,29 +1,63 @@
+pub mod allow;
+pub mod backup;
 pub mod check;
 pub mod completion;
+pub mod copy;
+pub mod copy_var;
 pub mod create;
 pub mod delete;
 pub mod edit;
 pub mod export;
+pub mod grep;
+pub mod hook;
 pub mod import;
 pub mod init;
 pub mod keyring;
+pub mod lint;
 pub mod list;
+pub mod migrate;
 pub mod rotate_key;
+pub mod rotate_var;
 pub mod run;
+pub mod scan;
+pub mod schema;
 pub mod set;
+pub mod settings;
 pub mod shell;
 pub mod show;
 pub mod tui;
 pub mod unset;
+pub mod use_profile;
 pub mod version;
 
+use std::{path::PathBuf, time::Duration};
+
+use envio::{Sensitivity, environment::EnvironmentOptions};
+
 use crate::{
-    clap_app::{ClapApp, Command},
+    child,
+    clap_app::{ClapApp, Command, ConfigAction, EnvironmentArgs, SchemaAction},
+    config,
     diagnostic::DiagnosticReport,
-    error::AppResult,
+    error::{AppError, AppResult},
+    profile_ops, prompts,
 };
 
 impl ClapApp {
+    /// applies the global options and loads the settings
+    pub fn init(&self) -> AppResult<()> {
+        config::set_overrides(self.dir.as_ref().map(PathBuf::from), self.global);
+
+        // `envio config` has to work with a broken config so it can be fixed
+        if !matches!(self.command, Command::Config { .. }) {
+            crate::settings::init()?;
+        }
+
+        prompts::set_no_input(self.no_input);
+
+        Ok(())
+    }
+
     pub fn run(&self) -> AppResult<()> {
         if self.diagnostic {
             DiagnosticReport::generate()?.print()?;
@@ -40,47 +74,172 @@ impl ClapApp {
                 cipher_kind,
                 comments,
                 expires,
+                no_comments,
+                no_expires,
+                secret,
             } => create::run(
                 profile_name,
                 description.as_deref(),
-                envs.as_deref(),
-                envs_file.as_deref(),
-                cipher_kind.as_deref(),
-                *comments,
-                *expires,
+                &create::CreateOptions {
+                    envs: envs.as_deref(),
+                    envs_file: envs_file.as_deref(),
+                    cipher_kind: cipher_kind.as_deref(),
+                    comments: *comments,
+                    expires: *expires,
+                    no_comments: *no_comments,
+                    no_expires: *no_expires,
+                    secret,
+                },
             ),
             Command::Edit { profile_name } => edit::run(profile_name),
             Command::Set {
-                profile_name,
-                envs,
+                args,
                 comments,
                 expires,
-            } => set::run(profile_name, envs, *comments, *expires),
-            Command::Unset { profile_name, keys } => unset::run(profile_name, keys),
+                secret,
+                plain,
+                tags,
+                labels,
+                rotate_every,
+                rotate_command,
+            } => {
+                // a leading `KEY=VALUE` can't be a profile name
+                let (profile_name, envs) = split_profile_arg(args, |arg| !arg.contains('='))?;
+                set::run(
+                    &profile_name,
+                    envs,
+                    &set::SetOptions {
+                        comments: *comments,
+                        expires: *expires,
+                        sensitivity: match (secret, plain) {
+                            (true, _) => Some(Sensitivity::Secret),
+                            (_, true) => Some(Sensitivity::Plain),
+                            _ => None,
+                        },
+                        tags: tags.as_deref(),
+                        labels,
+                        rotate_every: rotate_every.as_deref(),
+                        rotate_command: rotate_command.as_deref(),
+                    },
+                )
+            }
+            Command::Unset { args } => {
+                let (profile_name, keys) = split_profile_arg(args, |_| true)?;
+                unset::run(&profile_name, keys)
+            }
+            Command::MvVar {
+                from,
+                to,
+                keys,
+                force,
+            } => copy_var::run(from, to, keys, *force, true),
+            Command::CpVar {
+                from,
+                to,
+                keys,
+                force,
+            } => copy_var::run(from, to, keys, *force, false),
             Command::Show {
                 profile_name,
                 no_pretty_print,
                 show_comments,
                 show_expiration,
+                show_metadata,
+                tags,
+                reveal,
             } => show::run(
-                profile_name,
+                &profile_ops::resolve_profile_name(profile_name.as_deref())?,
                 *no_pretty_print,
-                *show_comments,
-                *show_expiration,
+                &show::ShowOptions {
+                    comments: *show_comments,
+                    expiration: *show_expiration,
+                    metadata: *show_metadata,
+                    tags,
+                    reveal: reveal.as_deref(),
+                },
             ),
-            Command::List { no_pretty_print } => list::run(*no_pretty_print),
+            Command::List { no_pretty_print } => list::run(*no_pretty_print, self.global),
             Command::Delete { profile_name } => delete::run(profile_name),
-            Command::Check { profile_name } => check::run(profile_name),
+            Command::Rename {
+                profile_name,
+                new_name,
+            } => copy::rename(profile_name, new_name),
+            Command::Copy {
+                profile_name,
+                new_name,
+                cipher_kind,
+                new_key,
+                description,
+            } => copy::copy(
+                profile_name,
+                new_name,
+                cipher_kind.as_deref(),
+                *new_key,
+                description.as_deref(),
+            ),
+            Command::Grep {
+                pattern,
+                keys,
+                values,
+                comments,
+                regex,
+                ignore_case,
+            } => grep::run(
+                pattern,
+                &grep::GrepOptions {
+                    keys: *keys,
+                    values: *values,
+                    comments: *comments,
+                    regex: *regex,
+                    ignore_case: *ignore_case,
+                },
+                self.global,
+            ),
+            Command::Scan {
+                paths,
+                staged,
+                min_length,
+            } => scan::run(&scan::ScanOptions {
+                paths: paths.clone(),
+                staged: *staged,
+                min_length: *min_length,
+            }),
+            Command::Lint {
+                profile_names,
+                fix,
+                disable,
+                strict,
+                list_rules,
+            } => {
+                if *list_rules {
+                    lint::list_rules()
+                } else {
+                    lint::run(profile_names, *fix, disable, *strict)
+                }
+            }
+            Command::Check { profile_name, all } => {
+                let profile_names = if *all {
+                    let mut names = config::collect_profile_names()?;
+                    names.sort();
+                    names
+                } else {
+                    vec![profile_ops::resolve_profile_name(profile_name.as_deref())?]
+                };
+
+                check::run(&profile_names)
+            }
             Command::Export {
                 profile_name,
                 output_file_path,
                 keys,
                 format,
+                tags,
             } => export::run(
-                profile_name,
+                &profile_ops::resolve_profile_name(profile_name.as_deref())?,
                 output_file_path.as_deref(),
                 keys.as_deref(),
-                format,
+                format.as_deref(),
+                tags,
             ),
             Command::Import {
                 source,
@@ -89,14 +248,160 @@ impl ClapApp {
             Command::AddKey { profile_name } => keyring::add_key(profile_name),
             Command::RemoveKey { profile_name } => keyring::remove_key(profile_name),
             Command::RotateKey { profile_name } => rotate_key::run(profile_name),
-            Command::Shell { profile_name } => shell::run(profile_name),
+            Command::RotateVar {
+                profile_name,
+                key,
+                expires,
+                rollback,
+                run_command,
+            } => rotate_var::run(
+                profile_name,
+                key,
+                expires.as_deref(),
+                *rollback,
+                *run_command,
+            ),
+            Command::Shell {
+                profile_name,
+                environment,
+            } => shell::run(
+                &environment.profile_names(profile_name.as_deref())?,
+                &environment.into(),
+            ),
             Command::Run {
                 profile_name,
+                environment,
+                exec,
+                redact,
+                no_redact,
+                no_clean,
+                redact_encoded,
+                watch,
+                restart_signal,
+                grace_period,
+                on_change,
+                command,
+            } => run::run(
+                &environment.profile_names(profile_name.as_deref())?,
+                &environment.into(),
+                run::RunOptions {
+                    exec: *exec,
+                    redact: *redact,
+                    no_redact: *no_redact,
+                    no_clean: *no_clean,
+                    redact_encoded: *redact_encoded,
+                    watch: if *watch {
+                        Some(run::WatchOptions {
+                            restart_signal: child::parse_signal(restart_signal)?,
+                            grace_period: Duration::from_secs(*grace_period),
+                            on_change: on_change.clone(),
+                        })
+                    } else {
+                        None
+                    },
+                },
                 command,
-            } => run::run(profile_name, command),
+            ),
+            Command::Use {
+                profile_name,
+                clear,
+            } => use_profile::run(profile_name.as_deref(), *clear),
             Command::Tui => tui::run(),
             Command::Completion { shell } => completion::run(shell),
+            Command::Hook { shell } => hook::run(shell),
+            Command::HookEnv { shell } => hook::run_env(shell),
+            Command::Allow { dir, revoke } => allow::run(dir.as_deref(), *revoke),
+            Command::Config { action } => match action {
+                ConfigAction::Get { key } => settings::get(key),
+                ConfigAction::Set { key, value } => settings::set(key, value, self.global),
+                ConfigAction::Unset { key } => settings::unset(key, self.global),
+                ConfigAction::List => settings::list(),
+            },
+            Command::Migrate { backend } => migrate::run(backend, self.global),
+            Command::Backup {
+                output_file_path,
+                include_keys,
+                recipient,
+                force,
+            } => backup::backup(
+                output_file_path.as_deref(),
+                *include_keys,
+                recipient.as_deref(),
+                *force,
+                self.global,
+            ),
+            Command::Restore {
+                archive,
+                dry_run,
+                on_conflict,
+            } => backup::restore(archive, *dry_run, on_conflict.as_deref(), self.global),
+            Command::Schema { action } => match action {
+                SchemaAction::Generate {
+                    profile_name,
+                    force,
+                } => schema::generate(
+                    &profile_ops::resolve_profile_name(profile_name.as_deref())?,
+                    *force,
+                ),
+                SchemaAction::Example { output_file_path } => {
+                    schema::example(output_file_path.as_deref())
+                }
+            },
             Command::Version { verbose } => version::run(*verbose),
         }
     }
 }
+
+impl EnvironmentArgs {
+    /// the positional profile followed by every `--profile`, in load order,
+    /// or the default profile if none was given
+    fn profile_names(&self, profile_name: Option<&str>) -> AppResult<Vec<String>> {
+        if profile_name.is_none() && self.profiles.is_empty() {
+            return Ok(vec![profile_ops::resolve_profile_name(None)?]);
+        }
+
+        Ok(profile_name
+            .map(str::to_string)
+            .into_iter()
+            .chain(self.profiles.iter().cloned())
+            .collect())
+    }
+}
+
+/// splits the leading profile name off positional arguments when it names an
+/// existing profile, otherwise every argument is kept and the default profile is
+/// used. A leading argument that can't be a variable key, such as `prod-eu` or
+/// `staging`, has to name a profile so a typo never writes to the default one
+fn split_profile_arg(
+    args: &[String],
+    may_be_profile: impl Fn(&str) -> bool,
+) -> AppResult<(String, &[String])> {
+    if let [first, rest @ ..] = args
+        && !rest.is_empty()
+        && may_be_profile(first)
+    {
+        if config::profile_exists(first).unwrap_or(false) {
+            return Ok((first.clone(), rest));
+        }
+
+        if !envio::lint::is_posix_name(first) || first.chars().any(|c| c.is_ascii_lowercase()) {
+            return Err(AppError::ProfileDoesNotExist(first.clone()));
+        }
+    }
+
+    Ok((profile_ops::resolve_profile_name(None)?, args))
+}
+
+impl From<&EnvironmentArgs> for EnvironmentOptions {
+    fn from(args: &EnvironmentArgs) -> Self {
+        Self {
+            clean: args.clean,
+            keep: args.keep.clone(),
+            only: args.only.clone(),
+            except: args.except.clone(),
+            tags: args.tags.clone(),
+            prefix: args.prefix.clone(),
+            strip_prefix: args.strip_prefix.clone(),
+        }
+    }
+}
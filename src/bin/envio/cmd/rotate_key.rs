@@ -22,6 +22,7 @@ pub fn run(profile_name: &str) -> AppResult<()> {
             let selected_label = prompts::select_prompt(prompts::SelectPromptOptions {
                 title: "Select the new GPG key you want to use for encryption:".to_string(),
                 options: labels,
+                flag: None,
             })?;
 
             let fingerprint = available_keys
@@ -42,6 +43,7 @@ pub fn run(profile_name: &str) -> AppResult<()> {
                 min_length: Some(8),
                 with_confirmation: true,
                 confirmation_error_message: Some("The keys don't match".to_string()),
+                flag: None,
             })?;
             Zeroizing::new(key)
         }
@@ -0,0 +1,212 @@
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+use chrono::{Days, Local, NaiveDate};
+use envio::{Env, error::Error};
+
+use crate::{
+    error::{AppError, AppResult},
+    profile_ops, prompts, success_msg,
+};
+
+/// gives `key` a new value from its rotation command, or a prompt without one,
+/// the old value stays in the variable's history. `rollback` restores the value
+/// replaced last instead. The command comes from the profile, so it only runs
+/// once the user confirmed it or passed `run_confirmed`
+pub fn run(
+    profile_name: &str,
+    key: &str,
+    expires: Option<&str>,
+    rollback: bool,
+    run_confirmed: bool,
+) -> AppResult<()> {
+    let mut profile = profile_ops::get_profile_cli(profile_name)?;
+
+    let Some(env) = profile.envs.get_mut(key) else {
+        return Err(Error::EnvDoesNotExist(key.to_string()).into());
+    };
+
+    if rollback {
+        if !env.rollback() {
+            return Err(AppError::Msg(format!(
+                "'{}' has no previous value to restore",
+                key
+            )));
+        }
+
+        profile.save()?;
+        success_msg!("Restored the previous value of '{}'", key);
+        return Ok(());
+    }
+
+    let expires = expires
+        .map(|date| {
+            NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| {
+                AppError::Msg(format!(
+                    "Invalid expiration date '{}', expected YYYY-MM-DD",
+                    date
+                ))
+            })
+        })
+        .transpose()?;
+
+    let value = match env.rotation.as_ref().and_then(|r| r.command.as_deref()) {
+        Some(command) => {
+            if !run_confirmed {
+                confirm_command(key, command)?;
+            }
+            run_command(command, &env.value)?
+        }
+        None => prompt_value(env)?,
+    };
+
+    if value.is_empty() {
+        return Err(AppError::Msg(format!(
+            "The new value of '{}' is empty",
+            key
+        )));
+    }
+
+    if value == env.value {
+        return Err(AppError::Msg(format!(
+            "The new value of '{}' is the same as the old one",
+            key
+        )));
+    }
+
+    let expiration_date = match expires {
+        Some(date) => Some(date),
+        None => next_expiration(env)?,
+    };
+
+    env.rotate(value);
+    env.expiration_date = expiration_date;
+
+    profile.save()?;
+    success_msg!("Rotated '{}'", key);
+    Ok(())
+}
+
+/// shows `command` and asks before running it, without input `--run-command`
+/// is required whether prompts are disabled by `--no-input` or its variable
+fn confirm_command(key: &str, command: &str) -> AppResult<()> {
+    eprintln!("Rotation command of '{}': {}", key, command);
+
+    if !prompts::is_interactive() {
+        return Err(AppError::InputRequired {
+            prompt: "Run the rotation command".to_string(),
+            flag: Some("--run-command".to_string()),
+        });
+    }
+
+    let confirmed = prompts::confirm_prompt(prompts::ConfirmPromptOptions {
+        title: "Run this command?".to_string(),
+        default: Some(false),
+    })?;
+
+    if !confirmed {
+        return Err(AppError::Msg(format!(
+            "'{}' was not rotated, the command was not confirmed",
+            key
+        )));
+    }
+
+    Ok(())
+}
+
+/// runs `command` in the shell with `old_value` on stdin, its stdout without the
+/// trailing newline is the new value
+fn run_command(command: &str, old_value: &str) -> AppResult<String> {
+    #[cfg(target_family = "unix")]
+    let mut cmd = {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c").arg(command);
+        cmd
+    };
+
+    #[cfg(not(target_family = "unix"))]
+    let mut cmd = {
+        let mut cmd = Command::new("cmd");
+        cmd.arg("/C").arg(command);
+        cmd
+    };
+
+    let mut child = cmd
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|e| AppError::Msg(format!("Failed to run rotation command: {}", e)))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // a command that doesn't read the old value closes stdin early
+        let _ = stdin.write_all(old_value.as_bytes());
+    }
+
+    let output = child.wait_with_output()?;
+
+    if !output.status.success() {
+        return Err(AppError::Msg(format!(
+            "Rotation command exited with {}, the value was not changed",
+            output.status
+        )));
+    }
+
+    let value = String::from_utf8(output.stdout)
+        .map_err(|_| AppError::Msg("Rotation command printed invalid UTF-8".to_string()))?;
+
+    Ok(value
+        .strip_suffix('\n')
+        .map(|value| value.strip_suffix('\r').unwrap_or(value))
+        .unwrap_or(&value)
+        .to_string())
+}
+
+fn prompt_value(env: &Env) -> AppResult<String> {
+    let title = format!("Enter the new value for {}:", env.key);
+
+    if env.is_secret() {
+        prompts::password_prompt(prompts::PasswordPromptOptions {
+            title,
+            help_message: None,
+            min_length: Some(1),
+            with_confirmation: true,
+            confirmation_error_message: None,
+            flag: Some("the new value through a rotation command (`envio set --rotate-command`)"),
+        })
+    } else {
+        prompts::text_prompt(prompts::TextPromptOptions {
+            title,
+            default: None,
+            flag: Some("the new value through a rotation command (`envio set --rotate-command`)"),
+        })
+    }
+}
+
+/// with a rotation interval the new value expires after it. Otherwise a
+/// variable with an expiration date gets one picked by the user, suggesting the
+/// validity the old value had, and one without keeps having none
+fn next_expiration(env: &Env) -> AppResult<Option<NaiveDate>> {
+    let today = Local::now().date_naive();
+
+    if let Some(rotation) = &env.rotation {
+        return Ok(today.checked_add_days(Days::new(rotation.interval_days.into())));
+    }
+
+    let Some(expiration_date) = env.expiration_date else {
+        return Ok(None);
+    };
+
+    let validity = env
+        .updated_at
+        .or(env.created_at)
+        .map(|changed| expiration_date - changed.date_naive())
+        .filter(|validity| validity.num_days() > 0);
+
+    Ok(Some(prompts::date_prompt(prompts::DatePromptOptions {
+        title: format!("Select a new expiration date for '{}':", env.key),
+        default: validity.and_then(|validity| today.checked_add_signed(validity)),
+        flag: Some("--expires"),
+    })?))
+}
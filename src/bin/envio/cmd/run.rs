@@ -1,34 +1,306 @@
+use std::{
+    process::{Child, Stdio},
+    thread::JoinHandle,
+    time::Duration,
+};
+
+use envio::{Profile, environment::EnvironmentOptions};
 use indexmap::IndexMap;
 
 use crate::{
+    child,
     error::{AppError, AppResult},
-    profile_ops,
+    error_msg, profile_ops,
+    redact::{self, Redactor},
+    settings::settings,
+    success_msg, warning_msg,
+    watch::{self, EnvDiff, KeyCache, ProfileWatcher},
 };
 
-pub fn run(profile_name: &str, command: &[String]) -> AppResult<()> {
+pub struct RunOptions {
+    pub exec: bool,
+    pub redact: bool,
+    /// overrides `run.redact` from the config
+    pub no_redact: bool,
+    /// overrides `run.clean` from the config
+    pub no_clean: bool,
+    pub redact_encoded: bool,
+    pub watch: Option<WatchOptions>,
+}
+
+pub struct WatchOptions {
+    pub restart_signal: i32,
+    pub grace_period: Duration,
+    pub on_change: Option<String>,
+}
+
+pub fn run(
+    profile_names: &[String],
+    environment_options: &EnvironmentOptions,
+    mut options: RunOptions,
+    command: &[String],
+) -> AppResult<()> {
     if command.is_empty() {
         return Err(AppError::Msg("Command cannot be empty".to_string()));
     }
 
+    let defaults = &settings().run;
+    let environment_options = &EnvironmentOptions {
+        clean: environment_options.clean || (defaults.clean && !options.no_clean),
+        keep: defaults
+            .keep
+            .iter()
+            .chain(&environment_options.keep)
+            .cloned()
+            .collect(),
+        ..environment_options.clone()
+    };
+    options.redact |= defaults.redact && !options.exec && !options.no_redact;
+
+    if options.redact_encoded && !options.redact {
+        return Err(AppError::Msg(
+            "--redact-encoded needs redaction, pass --redact or set run.redact in the config"
+                .to_string(),
+        ));
+    }
+
+    if let Some(watch_options) = &options.watch {
+        return run_watching(
+            profile_names,
+            environment_options,
+            &options,
+            watch_options,
+            command,
+        );
+    }
+
     let program = &command[0];
     let args = &command[1..];
 
-    let profile = profile_ops::get_profile_cli(profile_name)?;
+    let profiles = profile_ops::get_profiles_cli(profile_names)?;
+    let environment = profile_ops::build_environment_cli(&profiles, environment_options)?;
+
+    let mut child = std::process::Command::new(program);
+    if environment_options.clean {
+        child.env_clear();
+    }
+
+    child.envs(environment).args(args);
+
+    if options.exec {
+        #[cfg(target_family = "unix")]
+        return Err(child::exec(&mut child));
+
+        #[cfg(not(target_family = "unix"))]
+        return Err(AppError::Msg(
+            "--exec is only supported on unix systems".to_string(),
+        ));
+    }
+
+    let redactor = options
+        .redact
+        .then(|| build_redactor(&profiles, options.redact_encoded))
+        .filter(|redactor| !redactor.is_empty());
+
+    let code = match redactor {
+        Some(redactor) => {
+            child.stdout(Stdio::piped()).stderr(Stdio::piped());
+            child::spawn_and_wait_with(&mut child, |spawned| {
+                redact::pipe_output(spawned, &redactor)
+            })?
+        }
+        None => {
+            child.stdout(Stdio::inherit()).stderr(Stdio::inherit());
+            child::spawn_and_wait(&mut child)?
+        }
+    };
+
+    std::process::exit(code)
+}
+
+fn build_redactor(profiles: &[Profile], include_encoded: bool) -> Redactor {
+    let mut secrets = Vec::new();
+
+    for env in profiles.iter().flat_map(|profile| &profile.envs) {
+        if include_encoded {
+            secrets.extend(redact::encoded_forms(&env.value));
+        }
+        secrets.push(env.value.clone());
+    }
+
+    Redactor::new(secrets)
+}
+
+/// a running child together with the threads copying its redacted output
+struct Running {
+    child: Child,
+    handles: Vec<JoinHandle<()>>,
+}
+
+impl Running {
+    fn spawn(
+        command: &[String],
+        environment: &IndexMap<String, String>,
+        clean: bool,
+        redactor: Option<&Redactor>,
+    ) -> AppResult<Self> {
+        let mut cmd = std::process::Command::new(&command[0]);
+        if clean {
+            cmd.env_clear();
+        }
+
+        cmd.envs(environment).args(&command[1..]);
+
+        if redactor.is_some() {
+            cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
+        }
+
+        let mut child = cmd
+            .spawn()
+            .map_err(|e| AppError::Msg(format!("Failed to spawn command: {}", e)))?;
 
-    let status = std::process::Command::new(program)
-        .envs::<IndexMap<String, String>, _, _>(profile.envs.into())
-        .args(args)
-        .stdout(std::process::Stdio::inherit())
-        .stderr(std::process::Stdio::inherit())
-        .spawn()
-        .map_err(|e| AppError::Msg(format!("Failed to spawn command: {}", e)))?
-        .wait()
-        .map_err(|e| AppError::Msg(format!("Failed to wait on command: {}", e)))?;
+        let handles = redactor
+            .map(|redactor| redact::pipe_output(&mut child, redactor))
+            .unwrap_or_default();
 
-    match status.code() {
-        Some(code) => std::process::exit(code),
-        None => Err(AppError::Msg(
-            "The child process was terminated by a signal".to_string(),
-        )),
+        Ok(Self { child, handles })
     }
+
+    fn join(self) {
+        for handle in self.handles {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn run_watching(
+    profile_names: &[String],
+    environment_options: &EnvironmentOptions,
+    options: &RunOptions,
+    watch_options: &WatchOptions,
+    command: &[String],
+) -> AppResult<()> {
+    let mut keys = KeyCache::default();
+    let mut profiles = keys.get_profiles(profile_names)?;
+    let mut environment = profile_ops::build_environment_cli(&profiles, environment_options)?;
+
+    let mut watcher = ProfileWatcher::new(profile_names.to_vec());
+
+    let redactor = |profiles: &[Profile]| {
+        options
+            .redact
+            .then(|| build_redactor(profiles, options.redact_encoded))
+            .filter(|redactor| !redactor.is_empty())
+    };
+
+    #[cfg(target_family = "unix")]
+    let forwarder = child::unix::SignalForwarder::install()?;
+
+    let mut running = Running::spawn(
+        command,
+        &environment,
+        environment_options.clean,
+        redactor(&profiles).as_ref(),
+    )?;
+
+    #[cfg(target_family = "unix")]
+    forwarder.forward_to(running.child.id());
+
+    loop {
+        std::thread::sleep(watch::POLL_INTERVAL);
+
+        if let Some(status) = running.child.try_wait()? {
+            #[cfg(target_family = "unix")]
+            forwarder.close();
+
+            running.join();
+            std::process::exit(child::exit_code(status));
+        }
+
+        if !watcher.poll() {
+            continue;
+        }
+
+        let reloaded = match keys.get_profiles(profile_names) {
+            Ok(reloaded) => reloaded,
+            Err(e) => {
+                error_msg!(
+                    "Failed to reload profiles, keeping the current environment: {}",
+                    e
+                );
+                continue;
+            }
+        };
+
+        let new_environment = profile_ops::build_environment_cli(&reloaded, environment_options)?;
+        let diff = EnvDiff::between(&environment, &new_environment);
+
+        profiles = reloaded;
+        if diff.is_empty() {
+            continue;
+        }
+
+        environment = new_environment;
+        success_msg!("Profile changed ({})", diff);
+
+        if let Some(on_change) = &watch_options.on_change {
+            run_on_change(on_change, &environment, environment_options.clean)?;
+            continue;
+        }
+
+        child::stop(
+            &mut running.child,
+            watch_options.restart_signal,
+            watch_options.grace_period,
+        )?;
+        running.join();
+
+        running = Running::spawn(
+            command,
+            &environment,
+            environment_options.clean,
+            redactor(&profiles).as_ref(),
+        )?;
+
+        #[cfg(target_family = "unix")]
+        forwarder.forward_to(running.child.id());
+    }
+}
+
+fn run_on_change(
+    on_change: &str,
+    environment: &IndexMap<String, String>,
+    clean: bool,
+) -> AppResult<()> {
+    #[cfg(target_family = "unix")]
+    let mut cmd = {
+        let mut cmd = std::process::Command::new("sh");
+        cmd.arg("-c").arg(on_change);
+        cmd
+    };
+
+    #[cfg(not(target_family = "unix"))]
+    let mut cmd = {
+        let mut cmd = std::process::Command::new("cmd");
+        cmd.arg("/C").arg(on_change);
+        cmd
+    };
+
+    if clean {
+        cmd.env_clear();
+    }
+
+    let status = cmd
+        .envs(environment)
+        .status()
+        .map_err(|e| AppError::Msg(format!("Failed to run on-change command: {}", e)))?;
+
+    if !status.success() {
+        warning_msg!(
+            "On-change command exited with error code: {}",
+            child::exit_code(status)
+        );
+    }
+
+    Ok(())
 }
@@ -0,0 +1,329 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use colored::Colorize;
+use comfy_table::{Attribute, Cell, ContentArrangement, Table};
+use regex::bytes::RegexBuilder;
+use serde::Serialize;
+
+use crate::{
+    config,
+    error::{AppError, AppResult},
+    output::{self, OutputFormat},
+    profile_ops, redact, warning_msg,
+};
+
+/// files with a NUL byte in this many leading bytes are treated as binary
+const BINARY_CHECK_LENGTH: usize = 8000;
+
+pub struct ScanOptions {
+    pub paths: Vec<String>,
+    /// scan the content staged for the next commit instead of the working tree
+    pub staged: bool,
+    pub min_length: usize,
+}
+
+/// where a value searched for comes from
+struct Owner {
+    profile: String,
+    key: String,
+    form: &'static str,
+}
+
+#[derive(Serialize)]
+struct Finding {
+    file: String,
+    line: usize,
+    profile: String,
+    key: String,
+    /// `plain`, `base64` or `url-encoded`
+    form: &'static str,
+}
+
+pub fn run(options: &ScanOptions) -> AppResult<()> {
+    let envio_dir = config::get_envio_dir()?;
+    let owners = collect_secrets(options.min_length)?;
+
+    let mut findings = Vec::new();
+
+    if !owners.is_empty() {
+        // longest first so a secret containing another one is reported as itself
+        let mut secrets: Vec<&String> = owners.keys().collect();
+        secrets.sort_by_key(|secret| std::cmp::Reverse(secret.len()));
+
+        let pattern = secrets
+            .iter()
+            .map(|secret| regex::escape(secret))
+            .collect::<Vec<_>>()
+            .join("|");
+        let regex = RegexBuilder::new(&pattern)
+            .size_limit(1 << 28)
+            .build()
+            .map_err(|e| AppError::Msg(format!("Too many secrets to scan for: {}", e)))?;
+
+        for (file, content) in files(options, &envio_dir)? {
+            if content[..content.len().min(BINARY_CHECK_LENGTH)].contains(&0) {
+                continue;
+            }
+
+            let mut line = 1;
+            let mut position = 0;
+
+            for m in regex.find_iter(&content) {
+                line += content[position..m.start()]
+                    .iter()
+                    .filter(|&&byte| byte == b'\n')
+                    .count();
+                position = m.start();
+
+                let secret = String::from_utf8_lossy(m.as_bytes());
+                for owner in owners.get(secret.as_ref()).into_iter().flatten() {
+                    findings.push(Finding {
+                        file: file.clone(),
+                        line,
+                        profile: owner.profile.clone(),
+                        key: owner.key.clone(),
+                        form: owner.form,
+                    });
+                }
+            }
+        }
+    }
+
+    print(&findings)?;
+
+    if !findings.is_empty() {
+        return Err(AppError::SecretsFound(findings.len()));
+    }
+
+    Ok(())
+}
+
+/// the values of the project's profiles with their encoded forms, skipping the
+/// profiles that can't be unlocked
+fn collect_secrets(min_length: usize) -> AppResult<HashMap<String, Vec<Owner>>> {
+    let mut owners: HashMap<String, Vec<Owner>> = HashMap::new();
+    let mut skipped = Vec::new();
+
+    for profile_name in config::collect_profile_names()? {
+        let profile = match profile_ops::decrypt_profile(&profile_name) {
+            Ok(profile) => profile,
+            Err(e @ AppError::Prompt(_)) => return Err(e),
+            Err(e) => {
+                skipped.push(format!("{}: {}", profile_name, e));
+                continue;
+            }
+        };
+
+        for env in &profile.envs {
+            if env.value.chars().count() < min_length {
+                continue;
+            }
+
+            let forms = std::iter::once(("plain", env.value.clone()))
+                .chain(redact::labeled_encoded_forms(&env.value));
+
+            for (form, secret) in forms {
+                owners.entry(secret).or_default().push(Owner {
+                    profile: profile_name.clone(),
+                    key: env.key.clone(),
+                    form,
+                });
+            }
+        }
+    }
+
+    if !skipped.is_empty() {
+        warning_msg!(
+            "{} profile(s) could not be unlocked and their values were not searched for:",
+            skipped.len()
+        );
+        for skipped in skipped {
+            eprintln!("  {}", skipped);
+        }
+    }
+
+    Ok(owners)
+}
+
+/// the files to scan with their content, the `.envio` folder is left out
+fn files(options: &ScanOptions, envio_dir: &Path) -> AppResult<Vec<(String, Vec<u8>)>> {
+    let in_repository = git(&["rev-parse", "--is-inside-work-tree"], &[]).is_ok();
+
+    if options.staged {
+        if !in_repository {
+            return Err(AppError::Msg("--staged needs a git repository".to_string()));
+        }
+
+        let names = git(
+            &[
+                "diff",
+                "--cached",
+                "--name-only",
+                "--relative",
+                "-z",
+                "--diff-filter=ACMR",
+                "--",
+            ],
+            &options.paths,
+        )?;
+
+        return split_names(&names)
+            .filter(|name| !is_inside(Path::new(name), envio_dir))
+            .map(|name| {
+                // `:./path` is the staged content of a path relative to the current directory
+                let content = git(&["show", &format!(":./{}", name)], &[])?;
+                Ok((name, content))
+            })
+            .collect();
+    }
+
+    let paths: Vec<PathBuf> = if in_repository {
+        let names = git(
+            &[
+                "ls-files",
+                "--cached",
+                "--others",
+                "--exclude-standard",
+                "-z",
+                "--",
+            ],
+            &options.paths,
+        )?;
+        split_names(&names).map(PathBuf::from).collect()
+    } else {
+        // outside of a git repository there is no .gitignore to respect
+        let mut paths = Vec::new();
+        let roots = if options.paths.is_empty() {
+            vec![".".to_string()]
+        } else {
+            options.paths.clone()
+        };
+        for root in roots {
+            walk(Path::new(&root), &mut paths)?;
+        }
+        paths
+    };
+
+    let mut files = Vec::new();
+
+    for path in paths {
+        // listed by git but deleted in the working tree
+        if !path.is_file() || is_inside(&path, envio_dir) {
+            continue;
+        }
+
+        let name = path
+            .strip_prefix(".")
+            .unwrap_or(&path)
+            .display()
+            .to_string();
+        files.push((name, std::fs::read(&path)?));
+    }
+
+    Ok(files)
+}
+
+/// stdout of `git args... extra...`
+fn git(args: &[&str], extra: &[String]) -> AppResult<Vec<u8>> {
+    let output = Command::new("git")
+        .args(args)
+        .args(extra)
+        .output()
+        .map_err(|e| AppError::Msg(format!("Failed to run git: {}", e)))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(AppError::Msg(format!(
+            "git {} failed: {}",
+            args[0],
+            stderr.lines().next().unwrap_or_default()
+        )));
+    }
+
+    Ok(output.stdout)
+}
+
+fn split_names(names: &[u8]) -> impl Iterator<Item = String> + '_ {
+    names
+        .split(|&byte| byte == 0)
+        .filter(|name| !name.is_empty())
+        .map(|name| String::from_utf8_lossy(name).to_string())
+}
+
+fn walk(path: &Path, files: &mut Vec<PathBuf>) -> AppResult<()> {
+    if path.is_file() {
+        files.push(path.to_path_buf());
+        return Ok(());
+    }
+
+    for entry in std::fs::read_dir(path)? {
+        let path = entry?.path();
+
+        if path.file_name().is_some_and(|name| name == ".git") {
+            continue;
+        }
+
+        if path.is_dir() {
+            walk(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+fn is_inside(path: &Path, dir: &Path) -> bool {
+    match (path.canonicalize(), dir.canonicalize()) {
+        (Ok(path), Ok(dir)) => path.starts_with(dir),
+        _ => false,
+    }
+}
+
+fn print(findings: &[Finding]) -> AppResult<()> {
+    let format = output::format();
+
+    if format.is_machine_readable() {
+        return output::print("scan", findings);
+    }
+
+    if format == OutputFormat::Plain {
+        for finding in findings {
+            println!(
+                "{}:{}\t{}\t{}\t{}",
+                finding.file, finding.line, finding.profile, finding.key, finding.form
+            );
+        }
+        return Ok(());
+    }
+
+    if findings.is_empty() {
+        println!("{}", "No profile secrets found.".bold());
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+    table.set_header(vec![
+        Cell::new("Location").add_attribute(Attribute::Bold),
+        Cell::new("Profile").add_attribute(Attribute::Bold),
+        Cell::new("Variable").add_attribute(Attribute::Bold),
+        Cell::new("Found As").add_attribute(Attribute::Bold),
+    ]);
+
+    for finding in findings {
+        table.add_row(vec![
+            Cell::new(format!("{}:{}", finding.file, finding.line)),
+            Cell::new(&finding.profile),
+            Cell::new(&finding.key),
+            Cell::new(finding.form),
+        ]);
+    }
+
+    println!("{table}");
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use std::path::PathBuf;
+
+use crate::{
+    config,
+    error::{AppError, AppResult},
+    profile_ops,
+    schema::{self, Schema},
+    success_msg,
+};
+
+pub fn generate(profile_name: &str, force: bool) -> AppResult<()> {
+    let path = schema::schema_path()?;
+
+    if path.exists() && !force {
+        return Err(AppError::Msg(format!(
+            "{} already exists, pass --force to overwrite it",
+            path.display()
+        )));
+    }
+
+    let profile = profile_ops::decrypt_profile(profile_name)?;
+    let schema = Schema::generate(&profile.envs);
+
+    std::fs::write(&path, toml::to_string_pretty(&schema)?)?;
+    success_msg!(
+        "Wrote {} with {} variables, review it before committing",
+        path.display(),
+        schema.vars.len()
+    );
+    Ok(())
+}
+
+pub fn example(output_file_path: Option<&str>) -> AppResult<()> {
+    let schema = schema::load()?.ok_or_else(|| {
+        AppError::Msg("No schema found, create one with `envio schema generate`".to_string())
+    })?;
+
+    let path = match output_file_path {
+        Some(path) => PathBuf::from(path),
+        None => config::get_envio_dir()?
+            .parent()
+            .map(|dir| dir.join(".env.example"))
+            .unwrap_or_else(|| PathBuf::from(".env.example")),
+    };
+
+    std::fs::write(&path, schema.example())?;
+    success_msg!("Wrote {}", path.display());
+    Ok(())
+}
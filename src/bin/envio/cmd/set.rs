@@ -1,39 +1,153 @@
 use chrono::Local;
-use envio::Env;
+use envio::{Env, EnvMap, Rotation, Sensitivity};
 
-use crate::{error::AppResult, profile_ops, prompts, success_msg};
+use crate::{
+    cmd::create,
+    error::{AppError, AppResult},
+    profile_ops, prompts, success_msg,
+};
 
-pub fn run(
-    profile_name: &str,
-    envs: &[String],
-    add_comments: bool,
-    add_expires: bool,
-) -> AppResult<()> {
+pub struct SetOptions<'a> {
+    pub comments: bool,
+    pub expires: bool,
+    /// `None` keeps the sensitivity of existing variables, new ones are plain
+    pub sensitivity: Option<Sensitivity>,
+    /// replaces the tags of the variables, `None` keeps them
+    pub tags: Option<&'a [String]>,
+    /// `KEY=VALUE` labels added to the variables, an empty value removes one
+    pub labels: &'a [String],
+    /// `90d`, `12w` or `off`, `None` keeps the rotation interval
+    pub rotate_every: Option<&'a str>,
+    /// an empty command removes it, `None` keeps it
+    pub rotate_command: Option<&'a str>,
+}
+
+pub fn run(profile_name: &str, envs: &[String], options: &SetOptions) -> AppResult<()> {
     let mut profile = profile_ops::get_profile_cli(profile_name)?;
+    let mut added = EnvMap::default();
+
+    let labels = options
+        .labels
+        .iter()
+        .map(|label| {
+            label.split_once('=').ok_or_else(|| {
+                AppError::Msg(format!("Invalid label '{}', expected KEY=VALUE", label))
+            })
+        })
+        .collect::<AppResult<Vec<_>>>()?;
+
+    let interval = options.rotate_every.map(parse_interval).transpose()?;
 
     for mut env in parse_envs(profile_name, &profile, envs)? {
-        if add_comments {
+        // a new value keeps what is known about the variable unless told otherwise
+        if let Some(existing) = profile.envs.get(&env.key) {
+            env.sensitivity = existing.sensitivity;
+            env.tags = existing.tags.clone();
+            env.labels = existing.labels.clone();
+            env.rotation = existing.rotation.clone();
+            env.history = existing.history.clone();
+        }
+
+        if let Some(sensitivity) = options.sensitivity {
+            env.sensitivity = sensitivity;
+        }
+
+        if let Some(tags) = options.tags {
+            env.tags = tags.to_vec();
+        }
+
+        for (key, value) in &labels {
+            if value.is_empty() {
+                env.labels.shift_remove(*key);
+            } else {
+                env.labels.insert(key.to_string(), value.to_string());
+            }
+        }
+
+        match interval {
+            Some(Some(interval_days)) => {
+                let command = env.rotation.take().and_then(|rotation| rotation.command);
+                env.rotation = Some(Rotation {
+                    interval_days,
+                    command,
+                });
+            }
+            Some(None) => env.rotation = None,
+            None => {}
+        }
+
+        if let Some(command) = options.rotate_command {
+            let Some(rotation) = env.rotation.as_mut() else {
+                return Err(AppError::Msg(format!(
+                    "'{}' has no rotation interval, pass --rotate-every as well",
+                    env.key
+                )));
+            };
+            rotation.command = (!command.is_empty()).then(|| command.to_string());
+        }
+
+        if options.comments {
             env.comment = Some(prompts::text_prompt(prompts::TextPromptOptions {
                 title: format!("Enter a comment for '{}':", env.key),
                 default: None,
+                flag: None,
             })?);
         }
 
-        if add_expires {
+        if options.expires {
             env.expiration_date = Some(prompts::date_prompt(prompts::DatePromptOptions {
                 title: format!("Select an expiration date for '{}':", env.key),
                 default: Some(Local::now().date_naive()),
+                flag: None,
             })?);
         }
 
+        added.insert(env.clone());
         profile.envs.insert(env);
     }
 
-    profile.save()?;
+    let encryption = create::check_unencrypted(profile_name, &added, profile.metadata.cipher_kind)?;
+
+    if let Some(encryption) = encryption {
+        profile.cipher = encryption.cipher;
+        profile.save()?;
+        create::store_key(
+            encryption.kind,
+            encryption.key.as_ref(),
+            &profile.metadata.uuid,
+        );
+    } else {
+        profile.save()?;
+    }
+
     success_msg!("Changes applied");
     Ok(())
 }
 
+/// days in `90d`, `12w` or `90`, `None` for `off`
+fn parse_interval(value: &str) -> AppResult<Option<u32>> {
+    if value == "off" {
+        return Ok(None);
+    }
+
+    let (amount, factor) = match value.strip_suffix('w') {
+        Some(weeks) => (weeks, 7),
+        None => (value.strip_suffix('d').unwrap_or(value), 1),
+    };
+
+    match amount
+        .parse::<u32>()
+        .ok()
+        .and_then(|n| n.checked_mul(factor))
+    {
+        Some(days) if days > 0 => Ok(Some(days)),
+        _ => Err(AppError::Msg(format!(
+            "Invalid rotation interval '{}', expected e.g. 90d, 12w or off",
+            value
+        ))),
+    }
+}
+
 fn parse_envs(
     _profile_name: &str,
     profile: &envio::Profile,
@@ -59,6 +173,7 @@ fn parse_envs(
                     raw
                 ),
                 default: None,
+                flag: Some("the value as KEY=VALUE"),
             })?;
             result.push(Env::from_key_value(raw.to_string(), value));
         }
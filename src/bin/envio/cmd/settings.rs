@@ -0,0 +1,117 @@
+use std::path::PathBuf;
+
+use colored::Colorize;
+use comfy_table::{Attribute, Cell, ContentArrangement, Table};
+
+use crate::{
+    error::{AppError, AppResult},
+    settings::{self, Source},
+    success_msg, warning_msg,
+};
+
+pub fn get(key: &str) -> AppResult<()> {
+    let key = settings::find_key(key)?;
+    let loaded = settings::load()?;
+    let effective = toml::Table::try_from(&loaded.settings)?;
+
+    if let Some(value) = settings::get_value(&effective, key.name) {
+        println!("{}", settings::display_value(&value));
+    }
+
+    Ok(())
+}
+
+pub fn set(key: &str, value: &str, global: bool) -> AppResult<()> {
+    let key = settings::find_key(key)?;
+    let value = settings::parse_value(key, value)
+        .map_err(|e| AppError::Msg(format!("Invalid value for `{}`: {}", key.name, e)))?;
+    let path = target_file(global || key.user_only)?;
+
+    let mut table = settings::read_table(&path)?;
+    settings::set_value(&mut table, key.name, value);
+    settings::validate(&table)
+        .map_err(|e| AppError::Msg(format!("Invalid value for `{}`: {}", key.name, e)))?;
+
+    write_table(&path, &table)?;
+    success_msg!("Set `{}` in {}", key.name, path.display());
+    Ok(())
+}
+
+pub fn unset(key: &str, global: bool) -> AppResult<()> {
+    let key = settings::find_key(key)?;
+    let path = target_file(global || key.user_only)?;
+
+    let mut table = settings::read_table(&path)?;
+    if !settings::remove_value(&mut table, key.name) {
+        warning_msg!("`{}` is not set in {}", key.name, path.display());
+        return Ok(());
+    }
+
+    write_table(&path, &table)?;
+    success_msg!("Unset `{}` in {}", key.name, path.display());
+    Ok(())
+}
+
+pub fn list() -> AppResult<()> {
+    let loaded = settings::load()?;
+    let effective = toml::Table::try_from(&loaded.settings)?;
+
+    let mut table = Table::new();
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+    table.set_header(vec![
+        Cell::new("Key").add_attribute(Attribute::Bold),
+        Cell::new("Value").add_attribute(Attribute::Bold),
+        Cell::new("Source").add_attribute(Attribute::Bold),
+        Cell::new("Description").add_attribute(Attribute::Bold),
+    ]);
+
+    for key in settings::KEYS {
+        let value = settings::get_value(&effective, key.name)
+            .map(|value| settings::display_value(&value))
+            .unwrap_or_default();
+        let source = loaded
+            .sources
+            .get(key.name)
+            .copied()
+            .unwrap_or(Source::Default);
+
+        table.add_row(vec![
+            key.name.to_string(),
+            value,
+            source.to_string(),
+            key.description.to_string(),
+        ]);
+    }
+
+    println!("{table}");
+
+    if let Some(path) = settings::project_config_path() {
+        println!("{} {}", "Project config:".bold(), path.display());
+    }
+    println!(
+        "{} {}",
+        "User config:".bold(),
+        settings::user_config_path().display()
+    );
+
+    Ok(())
+}
+
+/// the project config unless `user` is set or there is no project
+fn target_file(user: bool) -> AppResult<PathBuf> {
+    if !user && let Some(path) = settings::project_config_path() {
+        return Ok(path);
+    }
+
+    Ok(settings::user_config_path())
+}
+
+fn write_table(path: &PathBuf, table: &toml::Table) -> AppResult<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let contents = toml::to_string_pretty(table)?;
+    std::fs::write(path, contents)?;
+    Ok(())
+}
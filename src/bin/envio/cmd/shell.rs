@@ -1,12 +1,15 @@
-use indexmap::IndexMap;
+use envio::environment::EnvironmentOptions;
 
 use crate::{
+    child,
     error::{AppError, AppResult},
     profile_ops, success_msg,
 };
 
-pub fn run(profile_name: &str) -> AppResult<()> {
-    let profile = profile_ops::get_profile_cli(profile_name)?;
+pub fn run(profile_names: &[String], options: &EnvironmentOptions) -> AppResult<()> {
+    let profiles = profile_ops::get_profiles_cli(profile_names)?;
+    let environment = profile_ops::build_environment_cli(&profiles, options)?;
+    let names = profile_names.join("`, `");
 
     #[cfg(target_family = "windows")]
     let shell = std::env::var("COMSPEC").unwrap_or_else(|_| "cmd.exe".to_string());
@@ -14,27 +17,27 @@ pub fn run(profile_name: &str) -> AppResult<()> {
     #[cfg(target_family = "unix")]
     let shell = std::env::var("SHELL").unwrap_or_else(|_| "/bin/bash".to_string());
 
-    success_msg!(
-        "Starting a new shell session for profile `{}`",
-        profile_name
-    );
+    success_msg!("Starting a new shell session for profile `{}`", names);
 
-    let status = std::process::Command::new(&shell)
-        .envs::<IndexMap<String, String>, _, _>(profile.envs.into())
+    let mut child = std::process::Command::new(&shell);
+    if options.clean {
+        child.env_clear();
+    }
+
+    child
+        .envs(environment)
         .stdin(std::process::Stdio::inherit())
         .stdout(std::process::Stdio::inherit())
-        .stderr(std::process::Stdio::inherit())
-        .spawn()
-        .map_err(|e| AppError::Msg(format!("Failed to spawn shell: {}", e)))?
-        .wait()
-        .map_err(|e| AppError::Msg(format!("Failed to wait on shell: {}", e)))?;
+        .stderr(std::process::Stdio::inherit());
+
+    let code = child::spawn_and_wait(&mut child)?;
 
-    success_msg!("Exited shell session for profile `{}`", profile_name);
+    success_msg!("Exited shell session for profile `{}`", names);
 
-    if !status.success() {
+    if code != 0 {
         return Err(AppError::Msg(format!(
             "Shell exited with error code: {}",
-            status.code().unwrap_or(1)
+            code
         )));
     }
 
@@ -1,20 +1,76 @@
 use comfy_table::{Attribute, Cell, ContentArrangement, Table};
+use envio::{EnvMap, ProfileMetadata, error::Error};
+use serde::Serialize;
 
-use crate::{error::AppResult, profile_ops};
+use crate::{
+    error::AppResult,
+    output::{self, OutputFormat},
+    profile_ops, redact,
+};
 
-pub fn run(
-    profile_name: &str,
-    no_pretty_print: bool,
-    show_comments: bool,
-    show_expiration: bool,
-) -> AppResult<()> {
-    let profile = profile_ops::get_profile_cli(profile_name)?;
+#[derive(Serialize)]
+struct ProfileContents<'a> {
+    metadata: &'a ProfileMetadata,
+    envs: &'a EnvMap,
+}
+
+pub struct ShowOptions<'a> {
+    pub comments: bool,
+    pub expiration: bool,
+    /// tags, labels, the rotation interval and the last update
+    pub metadata: bool,
+    /// only variables with one of these tags, all of them when empty
+    pub tags: &'a [String],
+    /// secret values to display, all of them when empty
+    pub reveal: Option<&'a [String]>,
+}
+
+pub fn run(profile_name: &str, no_pretty_print: bool, options: &ShowOptions) -> AppResult<()> {
+    let mut profile = profile_ops::get_profile_cli(profile_name)?;
+    let reveal = options.reveal;
+
+    // `--reveal` without keys reveals every value
+    let revealed =
+        |key: &str| reveal.is_some_and(|keys| keys.is_empty() || keys.iter().any(|k| k == key));
+
+    for key in reveal.unwrap_or_default() {
+        if !profile.envs.contains_key(key) {
+            return Err(Error::EnvDoesNotExist(key.clone()).into());
+        }
+    }
+
+    if !options.tags.is_empty() {
+        profile
+            .envs
+            .retain(|env| options.tags.iter().any(|tag| env.has_tag(tag)));
+    }
 
-    if no_pretty_print {
-        for env in profile.envs {
-            println!("{}={}", env.key, env.value);
+    for env in profile.envs.iter_mut() {
+        if env.is_secret() && !revealed(&env.key) {
+            env.value = redact::MASKED.to_string();
+            for previous in &mut env.history {
+                previous.value = redact::MASKED.to_string();
+            }
         }
-        return Ok(());
+    }
+
+    match output::format_or_plain(no_pretty_print) {
+        OutputFormat::Json | OutputFormat::Yaml => {
+            return output::print(
+                "profile",
+                ProfileContents {
+                    metadata: &profile.metadata,
+                    envs: &profile.envs,
+                },
+            );
+        }
+        OutputFormat::Plain => {
+            for env in profile.envs {
+                println!("{}={}", env.key, env.value);
+            }
+            return Ok(());
+        }
+        OutputFormat::Table => {}
     }
 
     let mut table = Table::new();
@@ -25,24 +81,31 @@ pub fn run(
         Cell::new("Value").add_attribute(Attribute::Bold),
     ];
 
-    if show_comments {
+    if options.comments {
         header.push(Cell::new("Comment").add_attribute(Attribute::Bold));
     }
 
-    if show_expiration {
+    if options.expiration {
         header.push(Cell::new("Expiration Date").add_attribute(Attribute::Bold));
     }
 
+    if options.metadata {
+        header.push(Cell::new("Tags").add_attribute(Attribute::Bold));
+        header.push(Cell::new("Labels").add_attribute(Attribute::Bold));
+        header.push(Cell::new("Rotation").add_attribute(Attribute::Bold));
+        header.push(Cell::new("Updated").add_attribute(Attribute::Bold));
+    }
+
     table.set_header(header);
 
     for env in &profile.envs {
         let mut row = vec![env.key.clone(), env.value.clone()];
 
-        if show_comments {
+        if options.comments {
             row.push(env.comment.clone().unwrap_or_default());
         }
 
-        if show_expiration {
+        if options.expiration {
             row.push(
                 env.expiration_date
                     .map(|d| d.to_string())
@@ -50,6 +113,31 @@ pub fn run(
             );
         }
 
+        if options.metadata {
+            row.push(env.tags.join(", "));
+            row.push(
+                env.labels
+                    .iter()
+                    .map(|(key, value)| format!("{}={}", key, value))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            );
+            row.push(
+                env.rotation
+                    .as_ref()
+                    .map(|rotation| match env.rotation_due_date() {
+                        Some(date) => format!("every {}d, due {}", rotation.interval_days, date),
+                        None => format!("every {}d", rotation.interval_days),
+                    })
+                    .unwrap_or_default(),
+            );
+            row.push(
+                env.updated_at
+                    .map(|date| date.format("%Y-%m-%d %H:%M:%S").to_string())
+                    .unwrap_or_default(),
+            );
+        }
+
         table.add_row(row);
     }
 
@@ -0,0 +1,33 @@
+use crate::{
+    config,
+    error::{AppError, AppResult},
+    success_msg,
+};
+
+pub fn run(profile_name: Option<&str>, clear: bool) -> AppResult<()> {
+    if clear {
+        config::set_default_profile(None)?;
+        success_msg!("Cleared the default profile");
+        return Ok(());
+    }
+
+    let Some(profile_name) = profile_name else {
+        match config::get_default_profile()? {
+            Some(name) => println!("{}", name),
+            None => println!("No default profile set"),
+        }
+        return Ok(());
+    };
+
+    if profile_name.starts_with(config::GLOBAL_PREFIX) {
+        return Err(AppError::Msg(
+            "The default profile has to belong to the project".to_string(),
+        ));
+    }
+
+    config::require_profile(profile_name)?;
+    config::set_default_profile(Some(profile_name))?;
+
+    success_msg!("Using `{}` by default", profile_name);
+    Ok(())
+}
@@ -1,8 +1,41 @@
 use colored::Colorize;
+use serde::Serialize;
 
-use crate::error::AppResult;
+use crate::{
+    error::AppResult,
+    output::{self, OutputFormat},
+};
+
+#[derive(Serialize)]
+struct VersionInfo {
+    version: &'static str,
+    authors: &'static str,
+    license: &'static str,
+    repository: &'static str,
+    build_timestamp: &'static str,
+}
 
 pub fn run(verbose: bool) -> AppResult<()> {
+    match output::format() {
+        OutputFormat::Json | OutputFormat::Yaml => {
+            return output::print(
+                "version",
+                VersionInfo {
+                    version: env!("CARGO_PKG_VERSION"),
+                    authors: env!("CARGO_PKG_AUTHORS"),
+                    license: env!("CARGO_PKG_LICENSE"),
+                    repository: env!("CARGO_PKG_REPOSITORY"),
+                    build_timestamp: env!("BUILD_TIMESTAMP"),
+                },
+            );
+        }
+        OutputFormat::Plain if !verbose => {
+            println!("{}", env!("CARGO_PKG_VERSION"));
+            return Ok(());
+        }
+        _ => {}
+    }
+
     println!("{} {}", "Version".green(), env!("CARGO_PKG_VERSION"));
 
     if verbose {
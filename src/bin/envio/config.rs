@@ -1,70 +1,235 @@
-use std::path::PathBuf;
+use std::{
+    path::{Path, PathBuf},
+    sync::{Arc, OnceLock},
+};
 
-use envio::profile::{ProfileMetadata, SerializedProfile};
+use envio::{
+    Profile,
+    profile::ProfileMetadata,
+    store::{FileStore, ProfileStore},
+};
+use zeroize::Zeroizing;
 
 use crate::{
     error::{AppError, AppResult},
+    settings::{self, StorageBackend},
     utils::get_cwd,
 };
 
-pub fn get_profile_dir() -> AppResult<PathBuf> {
-    let envio_dir = get_cwd().join(".envio");
-    if !envio_dir.exists() {
-        return Err(AppError::Msg(
-            "Current directory has no .envio folder, run `envio init` first".to_string(),
-        ));
+/// prefix that addresses a profile in the global store, as in `~personal`
+pub const GLOBAL_PREFIX: char = '~';
+
+/// file inside `.envio` naming the project's default profile, set with `envio use`
+pub const ACTIVE_FILE: &str = "active";
+
+/// database inside `.envio` used by the sqlite storage backend
+#[cfg(feature = "sqlite")]
+pub const PROFILE_DB: &str = "profiles.db";
+
+#[derive(Default)]
+struct Overrides {
+    envio_dir: Option<PathBuf>,
+    global: bool,
+}
+
+static OVERRIDES: OnceLock<Overrides> = OnceLock::new();
+
+/// applies `--dir` and `--global`, must be called before any profile is looked up
+pub fn set_overrides(envio_dir: Option<PathBuf>, global: bool) {
+    let envio_dir = envio_dir.or_else(|| std::env::var_os("ENVIO_DIR").map(PathBuf::from));
+    let _ = OVERRIDES.set(Overrides { envio_dir, global });
+}
+
+fn overrides() -> &'static Overrides {
+    OVERRIDES.get_or_init(Overrides::default)
+}
+
+/// the `.envio` folder `envio init` creates
+pub fn init_dir() -> PathBuf {
+    overrides()
+        .envio_dir
+        .clone()
+        .unwrap_or_else(|| get_cwd().join(".envio"))
+}
+
+/// the `.envio` folder of the current project, the nearest one found walking up from
+/// the current directory unless `--dir` or `ENVIO_DIR` point somewhere else
+pub fn get_envio_dir() -> AppResult<PathBuf> {
+    if let Some(dir) = &overrides().envio_dir {
+        if !dir.is_dir() {
+            return Err(AppError::Msg(format!(
+                "{} does not exist, run `envio init` first",
+                dir.display()
+            )));
+        }
+
+        return Ok(dir.clone());
     }
-    Ok(envio_dir.join("profiles"))
+
+    get_cwd()
+        .ancestors()
+        .map(|dir| dir.join(".envio"))
+        .find(|dir| dir.is_dir())
+        .ok_or_else(|| {
+            AppError::Msg(
+                "No .envio folder found in the current directory or its parents, run `envio init` first"
+                    .to_string(),
+            )
+        })
+}
+
+pub fn get_profile_dir() -> AppResult<PathBuf> {
+    Ok(get_envio_dir()?.join("profiles"))
+}
+
+/// the user's envio folder, the global counterpart of a project's `.envio`
+pub fn get_global_dir() -> PathBuf {
+    dirs::config_dir()
+        .or_else(|| dirs::home_dir().map(|home| home.join(".config")))
+        .unwrap_or_else(get_cwd)
+        .join("envio")
+}
+
+pub fn is_global_profile(profile_name: &str) -> bool {
+    profile_name.starts_with(GLOBAL_PREFIX) || overrides().global
+}
+
+/// the profile name without the [`GLOBAL_PREFIX`]
+pub fn bare_profile_name(profile_name: &str) -> &str {
+    profile_name
+        .strip_prefix(GLOBAL_PREFIX)
+        .unwrap_or(profile_name)
 }
 
 pub fn contains_path_separator(s: &str) -> bool {
     s.contains('/') || s.contains('\\')
 }
 
-/// returns the path for a profile that does **not** exist yet
-pub fn build_profile_path(profile_name: &str) -> AppResult<PathBuf> {
-    Ok(get_profile_dir()?.join(format!("{profile_name}.envio")))
+/// the store that holds `profile_name` and the name of the profile inside it,
+/// without the [`GLOBAL_PREFIX`]
+pub fn locate_profile(profile_name: &str) -> AppResult<(Arc<dyn ProfileStore>, &str)> {
+    let name = bare_profile_name(profile_name);
+    if name.is_empty() {
+        return Err(AppError::Msg("Profile name cannot be empty".to_string()));
+    }
+
+    let store = if is_global_profile(profile_name) {
+        global_store()?
+    } else {
+        project_store()?
+    };
+
+    Ok((store, name))
 }
 
-/// returns the path for a profile that **must exist**
-pub fn get_profile_path(profile_name: &str) -> AppResult<PathBuf> {
-    let path = build_profile_path(profile_name)?;
+pub fn project_store() -> AppResult<Arc<dyn ProfileStore>> {
+    open_store(&get_envio_dir()?)
+}
 
-    if !path.exists() {
+pub fn global_store() -> AppResult<Arc<dyn ProfileStore>> {
+    open_store(&get_global_dir())
+}
+
+/// the store configured with `storage.backend` for the envio folder `envio_dir`
+pub fn open_store(envio_dir: &Path) -> AppResult<Arc<dyn ProfileStore>> {
+    open_backend(settings::storage_backend(envio_dir)?, envio_dir)
+}
+
+pub fn open_backend(backend: StorageBackend, envio_dir: &Path) -> AppResult<Arc<dyn ProfileStore>> {
+    match backend {
+        StorageBackend::File => Ok(Arc::new(FileStore::new(envio_dir.join("profiles")))),
+        #[cfg(feature = "sqlite")]
+        StorageBackend::Sqlite => Ok(Arc::new(envio::store::SqliteStore::open(
+            envio_dir.join(PROFILE_DB),
+        )?)),
+        #[cfg(not(feature = "sqlite"))]
+        StorageBackend::Sqlite => Err(AppError::Config(
+            "the sqlite storage backend needs envio built with the `sqlite` feature".to_string(),
+        )),
+    }
+}
+
+pub fn profile_exists(profile_name: &str) -> AppResult<bool> {
+    let (store, name) = locate_profile(profile_name)?;
+    Ok(store.exists(name)?)
+}
+
+/// [`envio::get_profile_from`] for a profile name as given on the command line
+pub fn open_profile<F>(profile_name: &str, key_provider: Option<F>) -> AppResult<Profile>
+where
+    F: FnOnce(&ProfileMetadata) -> envio::error::Result<Zeroizing<String>>,
+{
+    require_profile(profile_name)?;
+
+    let (store, name) = locate_profile(profile_name)?;
+    Ok(envio::get_profile_from(store, name, key_provider)?)
+}
+
+/// errors with [`AppError::ProfileDoesNotExist`] unless the profile exists
+pub fn require_profile(profile_name: &str) -> AppResult<()> {
+    if !profile_exists(profile_name)? {
         return Err(AppError::ProfileDoesNotExist(profile_name.to_string()));
     }
 
-    Ok(path)
+    Ok(())
 }
 
-pub fn get_profile_metadata(profile_name: &str) -> AppResult<ProfileMetadata> {
-    let path = get_profile_path(profile_name)?;
-    let serialized_profile: SerializedProfile = envio::utils::get_serialized_profile(path)?;
-    Ok(serialized_profile.metadata)
+/// the profile used when a command is not given one, `ENVIO_PROFILE` wins over `envio use`
+pub fn get_default_profile() -> AppResult<Option<String>> {
+    if let Ok(name) = std::env::var("ENVIO_PROFILE")
+        && !name.is_empty()
+    {
+        return Ok(Some(name));
+    }
+
+    get_active_profile()
 }
 
-pub fn collect_profile_names() -> AppResult<Vec<String>> {
-    let profile_dir = get_profile_dir()?;
-    let mut profiles = Vec::new();
+/// the profile set with `envio use`
+pub fn get_active_profile() -> AppResult<Option<String>> {
+    let Ok(envio_dir) = get_envio_dir() else {
+        return Ok(None);
+    };
 
-    if !profile_dir.exists() {
-        return Ok(profiles);
+    let path = envio_dir.join(ACTIVE_FILE);
+    if !path.exists() {
+        return Ok(None);
     }
 
-    for entry in std::fs::read_dir(&profile_dir)? {
-        let entry = entry?;
-        let path = entry.path();
+    let name = std::fs::read_to_string(path)?.trim().to_string();
+    Ok((!name.is_empty()).then_some(name))
+}
 
-        if path.extension().and_then(|s| s.to_str()) != Some("envio") {
-            continue;
-        }
+pub fn set_default_profile(profile_name: Option<&str>) -> AppResult<()> {
+    let path = get_envio_dir()?.join(ACTIVE_FILE);
 
-        if let Some(name) = path.file_stem().and_then(|s| s.to_str())
-            && !name.starts_with('.')
-        {
-            profiles.push(name.to_owned());
-        }
+    match profile_name {
+        Some(name) => std::fs::write(path, format!("{name}\n"))?,
+        None if path.exists() => std::fs::remove_file(path)?,
+        None => {}
     }
 
-    Ok(profiles)
+    Ok(())
+}
+
+pub fn get_profile_metadata(profile_name: &str) -> AppResult<ProfileMetadata> {
+    require_profile(profile_name)?;
+
+    let (store, name) = locate_profile(profile_name)?;
+    Ok(store.load_metadata(name)?)
+}
+
+/// names of the project's profiles
+pub fn collect_profile_names() -> AppResult<Vec<String>> {
+    Ok(project_store()?.list()?)
+}
+
+/// metadata of the project's profiles, a single query for stores that index it
+pub fn collect_profile_metadata() -> AppResult<Vec<ProfileMetadata>> {
+    Ok(project_store()?.list_metadata()?)
+}
+
+/// metadata of the profiles in the global store, names without the [`GLOBAL_PREFIX`]
+pub fn collect_global_profile_metadata() -> AppResult<Vec<ProfileMetadata>> {
+    Ok(global_store()?.list_metadata()?)
 }
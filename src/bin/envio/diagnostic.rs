@@ -1,24 +1,32 @@
 pub use git_version::git_version;
+use serde::Serialize;
 
-use crate::config::get_profile_dir;
+use crate::config::{collect_profile_names, get_envio_dir, get_profile_dir};
 use crate::error::AppResult;
+use crate::output;
+use crate::settings::storage_backend;
 
+#[derive(Serialize)]
 struct Code {
     language: Option<String>,
     code: String,
 }
 
+#[derive(Serialize)]
+#[serde(tag = "type", content = "value", rename_all = "lowercase")]
 enum DiagnosticEntry {
     Text(String),
     Code(Code),
     List(Vec<DiagnosticEntry>),
 }
 
+#[derive(Serialize)]
 struct DiagnosticSection<'a> {
     title: &'a str,
     entry: DiagnosticEntry,
 }
 
+#[derive(Serialize)]
 pub struct DiagnosticReport<'a> {
     sections: Vec<DiagnosticSection<'a>>,
 }
@@ -110,6 +118,10 @@ impl<'a> DiagnosticReport<'a> {
     }
 
     pub fn print(&self) -> AppResult<()> {
+        if output::format().is_machine_readable() {
+            return output::print("diagnostic", self);
+        }
+
         let mut output = String::new();
 
         for section in &self.sections {
@@ -150,15 +162,17 @@ fn get_config_info() -> AppResult<Vec<DiagnosticEntry>> {
     let mut info = vec![];
 
     let profile_dir = get_profile_dir()?;
+    let profile_count = collect_profile_names()?.len();
 
-    let entries = std::fs::read_dir(&profile_dir)?;
-
-    let profile_count = entries.count();
     info.push(DiagnosticEntry::Text(format!(
         "Profile directory: {} ({} profiles)",
         profile_dir.display(),
         profile_count
     )));
+    info.push(DiagnosticEntry::Text(format!(
+        "Storage backend: {}",
+        storage_backend(&get_envio_dir()?)?.as_str()
+    )));
 
     Ok(info)
 }
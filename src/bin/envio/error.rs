@@ -24,6 +24,12 @@ pub enum AppError {
     #[error(transparent)]
     Yaml(#[from] serde_yaml::Error),
 
+    #[error(transparent)]
+    TomlDe(#[from] toml::de::Error),
+
+    #[error(transparent)]
+    TomlSer(#[from] toml::ser::Error),
+
     #[error(transparent)]
     Prompt(#[from] inquire::InquireError),
 
@@ -36,6 +42,32 @@ pub enum AppError {
     #[error(transparent)]
     Reqwest(#[from] reqwest::Error),
 
+    #[error(
+        "`{prompt}` needs input but prompts are disabled, {}",
+        flag.as_ref().map_or("run it in an interactive terminal".to_string(), |flag| format!("pass {flag} instead"))
+    )]
+    InputRequired {
+        prompt: String,
+        flag: Option<String>,
+    },
+
+    #[error("Invalid config: {0}")]
+    Config(String),
+
+    #[error("Lint found {0} problem(s)")]
+    LintFailed(usize),
+
+    #[error("{0} variable(s) do not match the schema")]
+    SchemaViolation(usize),
+
+    #[error("Found {0} profile secret(s) in the scanned files")]
+    SecretsFound(usize),
+
+    #[error(
+        "Refusing to store {0} secret(s) in a profile without encryption, pick a cipher or change `secrets.unencrypted`"
+    )]
+    UnencryptedSecrets(usize),
+
     #[error("Unsupported shell: {0}")]
     UnsupportedShell(String),
 
@@ -43,9 +75,55 @@ pub enum AppError {
     Msg(String),
 }
 
+impl AppError {
+    /// exit code for the error, stable so scripts can branch on it
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            AppError::UnsupportedShell(_) => 2,
+            AppError::ProfileDoesNotExist(_) => 3,
+            AppError::ProfileExists(_) => 4,
+            AppError::EmptyProfile(_) => 5,
+            AppError::InputRequired { .. } => 6,
+            AppError::LintFailed(_) => 12,
+            AppError::SchemaViolation(_) => 13,
+            AppError::SecretsFound(_) => 14,
+            AppError::UnencryptedSecrets(_) => 15,
+            AppError::Library(e) => library_exit_code(e),
+            AppError::Config(_) | AppError::TomlDe(_) => 8,
+            AppError::Prompt(
+                inquire::InquireError::OperationCanceled
+                | inquire::InquireError::OperationInterrupted,
+            ) => 130,
+            _ => 1,
+        }
+    }
+}
+
+fn library_exit_code(e: &envio::error::Error) -> i32 {
+    use envio::error::Error;
+
+    match e {
+        Error::ProfileDoesNotExist(_) => 3,
+        Error::ProfileExists(_) => 4,
+        Error::Cipher(_) | Error::WrongKey => 7,
+        Error::Corrupted(_) => 9,
+        Error::UnsupportedVersion { .. } => 10,
+        Error::ExternalTool { .. } => 11,
+        Error::KeyProviderFailed(inner) => inner
+            .downcast_ref::<AppError>()
+            .map_or(1, AppError::exit_code),
+        _ => 1,
+    }
+}
+
+/// lets [`crate::profile_ops::resolve_key`] return app errors through the
+/// library, [`AppError::exit_code`] unwraps them again
 impl From<AppError> for envio::error::Error {
     fn from(e: AppError) -> Self {
-        Self::Msg(e.to_string())
+        match e {
+            AppError::Library(e) => e,
+            e => Self::KeyProviderFailed(Box::new(e)),
+        }
     }
 }
 
@@ -0,0 +1,108 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use envio::store::ProfileStore;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    config::{self, ACTIVE_FILE},
+    error::{AppError, AppResult},
+};
+
+/// nearest directory, starting at `start`, whose `.envio` folder names an active profile
+pub fn find_project(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .find(|dir| dir.join(".envio").join(ACTIVE_FILE).is_file())
+        .map(Path::to_path_buf)
+}
+
+pub fn active_profile(project: &Path) -> AppResult<String> {
+    let name = std::fs::read_to_string(project.join(".envio").join(ACTIVE_FILE))?
+        .trim()
+        .to_string();
+
+    if name.is_empty() || config::contains_path_separator(&name) {
+        return Err(AppError::Msg(format!(
+            "Invalid active profile `{}` in {}",
+            name,
+            project.display()
+        )));
+    }
+
+    Ok(name)
+}
+
+/// the profile store of `project`, which is not necessarily the current project
+pub fn store(project: &Path) -> AppResult<Arc<dyn ProfileStore>> {
+    config::open_store(&project.join(".envio"))
+}
+
+/// Hash over everything that decides what the hook loads for `project`.
+///
+/// It covers the project path, the active profile name and the stored profile itself,
+/// so any change to them has to be approved again with `envio allow`.
+pub fn content_hash(project: &Path) -> AppResult<String> {
+    let profile_name = active_profile(project)?;
+    let store = store(project)?;
+
+    if !store.exists(&profile_name)? {
+        return Err(AppError::ProfileDoesNotExist(profile_name));
+    }
+
+    // the same bytes a file store writes, so file approvals stay valid
+    let profile = serde_json::to_vec_pretty(&store.load(&profile_name)?)?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(project.as_os_str().as_encoded_bytes());
+    hasher.update([0]);
+    hasher.update(profile_name.as_bytes());
+    hasher.update([0]);
+    hasher.update(&profile);
+
+    Ok(to_hex(&hasher.finalize()))
+}
+
+pub fn is_allowed(project: &Path, hash: &str) -> bool {
+    std::fs::read_to_string(allow_file(project))
+        .map(|contents| contents.lines().next() == Some(hash))
+        .unwrap_or(false)
+}
+
+pub fn allow(project: &Path) -> AppResult<()> {
+    let hash = content_hash(project)?;
+    let file = allow_file(project);
+
+    if let Some(parent) = file.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    std::fs::write(file, format!("{}\n{}\n", hash, project.display()))?;
+    Ok(())
+}
+
+/// returns whether there was an approval to revoke
+pub fn revoke(project: &Path) -> AppResult<bool> {
+    match std::fs::remove_file(allow_file(project)) {
+        Ok(_) => Ok(true),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// approvals live outside the project so a cloned repo can't ship its own
+fn allow_file(project: &Path) -> PathBuf {
+    let name = to_hex(&Sha256::digest(project.as_os_str().as_encoded_bytes()));
+
+    dirs::data_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("envio")
+        .join("allow")
+        .join(name)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
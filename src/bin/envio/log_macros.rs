@@ -1,28 +1,30 @@
 #[macro_export]
 macro_rules! log_msg {
-    ($level:expr, $color:ident, $msg:expr) => {{
+    ($print:ident, $level:expr, $color:ident, $msg:expr) => {{
         use colored::Colorize;
         let label = stringify!($level).$color();
-        println!("{}: {}", label, $msg);
+        $print!("{}: {}", label, $msg);
     }};
-    ($level:expr, $color:ident, $fmt:expr, $($arg:tt)*) => {{
+    ($print:ident, $level:expr, $color:ident, $fmt:expr, $($arg:tt)*) => {{
         use colored::Colorize;
         let label = stringify!($level).$color();
-        println!("{}: {}", label, format!($fmt, $($arg)*));
+        $print!("{}: {}", label, format!($fmt, $($arg)*));
     }};
 }
 
 #[macro_export]
 macro_rules! success_msg {
-    ($($args:tt)*) => { $crate::log_msg!(Success, green, $($args)*) };
+    ($($args:tt)*) => { $crate::log_msg!(println, Success, green, $($args)*) };
 }
 
+// warnings and errors go to stderr so they never end up in output that is
+// evaluated by the shell hook
 #[macro_export]
 macro_rules! warning_msg {
-    ($($args:tt)*) => { $crate::log_msg!(Warning, yellow, $($args)*) };
+    ($($args:tt)*) => { $crate::log_msg!(eprintln, Warning, yellow, $($args)*) };
 }
 
 #[macro_export]
 macro_rules! error_msg {
-    ($($args:tt)*) => { $crate::log_msg!(Error, red, $($args)*) };
+    ($($args:tt)*) => { $crate::log_msg!(eprintln, Error, red, $($args)*) };
 }
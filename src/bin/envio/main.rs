@@ -1,16 +1,23 @@
+mod child;
 mod clap_app;
 mod cmd;
 mod completions;
 mod config;
 mod diagnostic;
 mod error;
+mod hook;
 mod log_macros;
+mod output;
 mod profile_ops;
 mod prompts;
+mod redact;
+mod schema;
+mod settings;
 mod tui;
 mod utils;
 #[cfg(not(debug_assertions))]
 mod version;
+mod watch;
 
 use clap::Parser;
 
@@ -34,18 +41,35 @@ fn check_for_updates() -> AppResult<()> {
 }
 
 fn run() -> AppResult<()> {
+    let app = ClapApp::parse();
+
+    if let Some(format) = &app.output {
+        output::set_format(output::OutputFormat::parse(format)?);
+    }
+
+    app.init()?;
+
+    // the hook runs on every prompt, it has to stay fast and quiet
     #[cfg(not(debug_assertions))]
-    check_for_updates()?;
+    if settings::settings().updates.check
+        && !matches!(app.command, clap_app::Command::HookEnv { .. })
+    {
+        check_for_updates()?;
+    }
 
-    ClapApp::parse().run()
+    app.run()
 }
 
 fn main() {
     match run() {
         Ok(_) => std::process::exit(0),
         Err(e) => {
-            error_msg!(e);
-            std::process::exit(1);
+            if output::format().is_machine_readable() {
+                output::print_error(&e);
+            } else {
+                error_msg!(e);
+            }
+            std::process::exit(e.exit_code());
         }
     }
 }
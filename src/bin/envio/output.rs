@@ -0,0 +1,110 @@
+use std::sync::OnceLock;
+
+use serde::Serialize;
+
+use crate::error::{AppError, AppResult};
+
+/// Version of the documents printed with `--output json|yaml`.
+///
+/// Bump it whenever a field is removed, renamed or changes meaning. Adding a
+/// field is not a breaking change.
+pub const SCHEMA_VERSION: u32 = 2;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    Table,
+    Plain,
+    Json,
+    Yaml,
+}
+
+impl OutputFormat {
+    pub fn parse(format: &str) -> AppResult<Self> {
+        match format {
+            "table" => Ok(Self::Table),
+            "plain" => Ok(Self::Plain),
+            "json" => Ok(Self::Json),
+            "yaml" => Ok(Self::Yaml),
+            _ => Err(AppError::Msg(format!(
+                "Unsupported output format: {}",
+                format
+            ))),
+        }
+    }
+
+    pub fn is_machine_readable(self) -> bool {
+        matches!(self, Self::Json | Self::Yaml)
+    }
+}
+
+static FORMAT: OnceLock<OutputFormat> = OnceLock::new();
+
+pub fn set_format(format: OutputFormat) {
+    let _ = FORMAT.set(format);
+}
+
+pub fn format() -> OutputFormat {
+    FORMAT.get().copied().unwrap_or(OutputFormat::Table)
+}
+
+/// the `--output` format, falling back to plain for commands that still take
+/// `--no-pretty-print`
+pub fn format_or_plain(no_pretty_print: bool) -> OutputFormat {
+    match FORMAT.get() {
+        Some(format) => *format,
+        None if no_pretty_print => OutputFormat::Plain,
+        None => OutputFormat::Table,
+    }
+}
+
+/// every document has the same envelope so consumers can check the version
+/// and the kind before looking at `data`
+#[derive(Serialize)]
+struct Document<'a, T: Serialize> {
+    schema_version: u32,
+    kind: &'a str,
+    data: T,
+}
+
+/// prints `data` to stdout in the selected machine readable format
+pub fn print<T: Serialize>(kind: &str, data: T) -> AppResult<()> {
+    println!("{}", render(kind, data)?);
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct ErrorData {
+    message: String,
+    exit_code: i32,
+}
+
+/// prints `e` to stderr as an `error` document on a single line, JSON in both
+/// formats since it is valid YAML as well
+pub fn print_error(e: &AppError) {
+    let document = Document {
+        schema_version: SCHEMA_VERSION,
+        kind: "error",
+        data: ErrorData {
+            message: e.to_string(),
+            exit_code: e.exit_code(),
+        },
+    };
+
+    match serde_json::to_string(&document) {
+        Ok(rendered) => eprintln!("{}", rendered),
+        Err(_) => crate::error_msg!(e),
+    }
+}
+
+fn render<T: Serialize>(kind: &str, data: T) -> AppResult<String> {
+    let document = Document {
+        schema_version: SCHEMA_VERSION,
+        kind,
+        data,
+    };
+
+    match format() {
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(&document)?.trim_end().to_string()),
+        _ => Ok(serde_json::to_string_pretty(&document)?),
+    }
+}
@@ -1,15 +1,24 @@
-use envio::{EnvMap, Profile, cipher::Cipher, get_profile, profile::ProfileMetadata};
+use envio::{
+    EnvMap, Profile,
+    cipher::Cipher,
+    environment::{EnvironmentOptions, build_environment, process_vars},
+    profile::ProfileMetadata,
+};
+use indexmap::IndexMap;
 use zeroize::Zeroizing;
 
 use crate::{
-    config::{self, build_profile_path, get_profile_path},
+    config,
     error::{AppError, AppResult},
-    prompts, warning_msg,
+    prompts, schema, warning_msg,
 };
 
-/// wrapper around [envio::get_profile] that prints expired env vars
+/// how many times a prompted key may be wrong before giving up
+const KEY_ATTEMPTS: usize = 3;
+
+/// wrapper around [envio::get_profile_from] that prints expired env vars
 pub fn get_profile_cli(profile_name: &str) -> AppResult<Profile> {
-    let profile = get_profile(config::get_profile_path(profile_name)?, Some(resolve_key))?;
+    let profile = decrypt_profile(profile_name)?;
 
     for env in profile.expired_envs() {
         warning_msg!("environment variable '{}' has expired", env.key);
@@ -18,27 +27,134 @@ pub fn get_profile_cli(profile_name: &str) -> AppResult<Profile> {
     Ok(profile)
 }
 
+pub fn get_profiles_cli(profile_names: &[String]) -> AppResult<Vec<Profile>> {
+    profile_names
+        .iter()
+        .map(|name| get_profile_cli(name))
+        .collect()
+}
+
+/// layers the profiles in order on top of the current environment, then fills
+/// in the schema defaults for whatever is still missing
+pub fn build_environment_cli(
+    profiles: &[Profile],
+    options: &EnvironmentOptions,
+) -> AppResult<IndexMap<String, String>> {
+    let mut environment = build_environment(
+        process_vars(),
+        profiles.iter().map(|profile| &profile.envs),
+        options,
+    );
+
+    if let Some(schema) = schema::load()? {
+        // defaults go through the same filters as the profiles and never replace
+        // a variable that is already set
+        let defaults = build_environment(std::iter::empty(), [&schema.defaults()], options);
+        for (key, value) in defaults {
+            environment.entry(key).or_insert(value);
+        }
+    }
+
+    Ok(environment)
+}
+
+/// the given profile, or else the default one, or else the one picked by the user
+pub fn resolve_profile_name(profile_name: Option<&str>) -> AppResult<String> {
+    if let Some(name) = profile_name {
+        return Ok(name.to_string());
+    }
+
+    if let Some(name) = config::get_default_profile()? {
+        return Ok(name);
+    }
+
+    let mut profiles = config::collect_profile_names()?;
+    if profiles.is_empty() {
+        return Err(AppError::Msg(
+            "No profiles found, create one with `envio create`".to_string(),
+        ));
+    }
+    profiles.sort();
+
+    prompts::select_prompt(prompts::SelectPromptOptions {
+        title: "Select a profile:".to_string(),
+        options: profiles,
+        flag: Some("a profile name (or set ENVIO_PROFILE)"),
+    })
+}
+
+/// [envio::get_profile_from] with [resolve_key], asking again when a prompted key is
+/// wrong and pointing at the culprit when a stored one is
+pub fn decrypt_profile(profile_name: &str) -> AppResult<Profile> {
+    for attempt in 1.. {
+        let mut source = None;
+        let result = config::open_profile(
+            profile_name,
+            Some(|meta: &ProfileMetadata| {
+                let (key, key_source) = find_key(meta)?;
+                source = Some(key_source);
+                Ok(key)
+            }),
+        );
+
+        let Err(AppError::Library(envio::error::Error::WrongKey)) = result else {
+            return result;
+        };
+
+        match source {
+            Some(KeySource::Prompt) if attempt < KEY_ATTEMPTS => {
+                warning_msg!("Wrong key, try again");
+            }
+            Some(KeySource::Env) => {
+                warning_msg!("ENVIO_KEY does not hold the key of `{}`", profile_name);
+                break;
+            }
+            Some(KeySource::Keyring) => {
+                warning_msg!(
+                    "The keyring holds the wrong key for `{}`, replace it with `envio add-key {}`",
+                    profile_name,
+                    profile_name
+                );
+                break;
+            }
+            _ => break,
+        }
+    }
+
+    Err(envio::error::Error::WrongKey.into())
+}
+
+enum KeySource {
+    Env,
+    Keyring,
+    Prompt,
+}
+
 pub fn resolve_key(meta: &ProfileMetadata) -> Result<Zeroizing<String>, envio::error::Error> {
+    Ok(find_key(meta)?.0)
+}
+
+fn find_key(meta: &ProfileMetadata) -> AppResult<(Zeroizing<String>, KeySource)> {
     if let Ok(key) = std::env::var("ENVIO_KEY") {
-        return Ok(Zeroizing::new(key));
+        return Ok((Zeroizing::new(key), KeySource::Env));
     }
 
     if let Ok(entry) = keyring::Entry::new("envio", &meta.uuid)
         && let Ok(pwd) = entry.get_password()
     {
-        return Ok(Zeroizing::new(pwd));
+        return Ok((Zeroizing::new(pwd), KeySource::Keyring));
     }
 
-    match prompts::password_prompt(prompts::PasswordPromptOptions {
+    let key = prompts::password_prompt(prompts::PasswordPromptOptions {
         title: "Enter your encryption key:".to_string(),
         help_message: Some("OH NO! you forgot your key! just kidding... or did you?".to_string()),
         min_length: None,
         with_confirmation: false,
         confirmation_error_message: None,
-    }) {
-        Ok(key) => Ok(Zeroizing::new(key)),
-        Err(e) => Err(e.into()),
-    }
+        flag: Some("ENVIO_KEY"),
+    })?;
+
+    Ok((Zeroizing::new(key), KeySource::Prompt))
 }
 
 pub fn create_profile(
@@ -47,24 +163,28 @@ pub fn create_profile(
     envs: EnvMap,
     cipher: Box<dyn Cipher>,
 ) -> AppResult<Profile> {
-    let profile_file_path = build_profile_path(&name)?;
+    let (store, bare_name) = config::locate_profile(&name)?;
 
-    if profile_file_path.exists() {
+    if store.exists(bare_name)? {
         return Err(AppError::ProfileExists(name));
     }
 
-    let mut profile = Profile::new(name, description, profile_file_path, envs, cipher);
+    let name = bare_name.to_string();
+    let mut profile = Profile::new(name, description, store, envs, cipher);
     profile.save()?;
 
     Ok(profile)
 }
 
 pub fn delete_profile(profile_name: &str) -> AppResult<()> {
-    if let Ok(metadata) = config::get_profile_metadata(profile_name) {
-        if let Ok(entry) = keyring::Entry::new("envio", &metadata.uuid) {
-            let _ = entry.delete_credential();
-        }
+    if let Ok(metadata) = config::get_profile_metadata(profile_name)
+        && let Ok(entry) = keyring::Entry::new("envio", &metadata.uuid)
+    {
+        let _ = entry.delete_credential();
     }
-    std::fs::remove_file(get_profile_path(profile_name)?)?;
+    config::require_profile(profile_name)?;
+
+    let (store, name) = config::locate_profile(profile_name)?;
+    store.delete(name)?;
     Ok(())
 }
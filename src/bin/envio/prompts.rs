@@ -1,4 +1,9 @@
-use std::{env, path::Path};
+use std::{
+    env,
+    io::IsTerminal,
+    path::Path,
+    sync::atomic::{AtomicBool, Ordering},
+};
 
 use colored::Colorize;
 use envio::cipher::CipherKind;
@@ -8,7 +13,34 @@ use inquire::{
 use regex::Regex;
 use strum::IntoEnumIterator;
 
-use crate::error::AppResult;
+use crate::{
+    error::{AppError, AppResult},
+    settings::settings,
+};
+
+static NO_INPUT: AtomicBool = AtomicBool::new(false);
+
+/// disables prompts, also done when stdin is not a terminal
+pub fn set_no_input(no_input: bool) {
+    let no_input = no_input || settings().prompt.no_input || !std::io::stdin().is_terminal();
+    NO_INPUT.store(no_input, Ordering::Relaxed);
+}
+
+pub fn is_interactive() -> bool {
+    !NO_INPUT.load(Ordering::Relaxed)
+}
+
+/// fails when prompts are disabled, `flag` is what the user can pass instead
+fn require_input(title: &str, flag: Option<&str>) -> AppResult<()> {
+    if is_interactive() {
+        return Ok(());
+    }
+
+    Err(AppError::InputRequired {
+        prompt: title.trim_end_matches(':').to_string(),
+        flag: flag.map(str::to_string),
+    })
+}
 
 #[derive(Clone, PartialEq)]
 pub enum CipherChoice {
@@ -25,10 +57,15 @@ impl std::fmt::Display for CipherChoice {
     }
 }
 
-pub fn select_cipher_kind_prompt() -> AppResult<CipherKind> {
+/// `allow_none` offers `none` too, for when encryption is optional
+pub fn select_cipher_kind_prompt(allow_none: bool) -> AppResult<CipherKind> {
     let has_gpg = envio::cipher::gpg::check_gpg().is_ok();
     let mut options = Vec::new();
     for kind in CipherKind::iter() {
+        if kind == CipherKind::NONE && !allow_none {
+            continue;
+        }
+
         if kind == CipherKind::GPG && !has_gpg {
             options.push(CipherChoice::GpgNotInstalled);
         } else {
@@ -40,6 +77,7 @@ pub fn select_cipher_kind_prompt() -> AppResult<CipherKind> {
         let selected = select_prompt(SelectPromptOptions {
             title: "Select the encryption method:".to_string(),
             options: options.clone(),
+            flag: Some("--cipher-kind"),
         })?;
 
         match selected {
@@ -60,6 +98,11 @@ pub struct ConfirmPromptOptions {
 }
 
 pub fn confirm_prompt(options: ConfirmPromptOptions) -> AppResult<bool> {
+    if let (false, Some(default)) = (is_interactive(), options.default) {
+        return Ok(default);
+    }
+    require_input(&options.title, None)?;
+
     let mut prompt = Confirm::new(&options.title);
 
     if let Some(default) = options.default {
@@ -75,9 +118,13 @@ pub struct PasswordPromptOptions {
     pub min_length: Option<usize>,
     pub with_confirmation: bool,
     pub confirmation_error_message: Option<String>,
+    /// flag or environment variable providing the value when prompts are disabled
+    pub flag: Option<&'static str>,
 }
 
 pub fn password_prompt(options: PasswordPromptOptions) -> AppResult<String> {
+    require_input(&options.title, options.flag)?;
+
     let mut prompt = Password::new(&options.title)
         .with_display_mode(PasswordDisplayMode::Masked)
         .with_display_toggle_enabled()
@@ -104,12 +151,16 @@ pub fn password_prompt(options: PasswordPromptOptions) -> AppResult<String> {
 pub struct SelectPromptOptions<T> {
     pub title: String,
     pub options: Vec<T>,
+    /// flag providing the choice when prompts are disabled
+    pub flag: Option<&'static str>,
 }
 
 pub fn select_prompt<T>(options: SelectPromptOptions<T>) -> AppResult<T>
 where
     T: std::fmt::Display,
 {
+    require_input(&options.title, options.flag)?;
+
     Ok(Select::new(&options.title, options.options)
         .with_vim_mode(get_vim_mode()?)
         .with_help_message("↑↓ to move, space to select, type to filter, enter to confirm")
@@ -119,9 +170,16 @@ where
 pub struct TextPromptOptions {
     pub title: String,
     pub default: Option<String>,
+    /// flag providing the value when prompts are disabled and there is no default
+    pub flag: Option<&'static str>,
 }
 
 pub fn text_prompt(options: TextPromptOptions) -> AppResult<String> {
+    if let (false, Some(default)) = (is_interactive(), &options.default) {
+        return Ok(default.clone());
+    }
+    require_input(&options.title, options.flag)?;
+
     let mut prompt = Text::new(&options.title);
 
     if let Some(ref default) = options.default {
@@ -133,12 +191,20 @@ pub fn text_prompt(options: TextPromptOptions) -> AppResult<String> {
 
 pub struct DatePromptOptions {
     pub title: String,
+    /// the date selected when the prompt opens, never used without input
     pub default: Option<chrono::NaiveDate>,
+    pub flag: Option<&'static str>,
 }
 
+/// fails when prompts are disabled, defaulting to today would save dates that
+/// have already passed
 pub fn date_prompt(options: DatePromptOptions) -> AppResult<chrono::NaiveDate> {
+    require_input(&options.title, options.flag)?;
+
+    let default = options.default.unwrap_or(chrono::Local::now().date_naive());
+
     Ok(DateSelect::new(&options.title)
-        .with_default(options.default.unwrap_or(chrono::Local::now().date_naive()))
+        .with_default(default)
         .prompt()?)
 }
 
@@ -152,6 +218,18 @@ pub fn multi_select_prompt<T>(options: MultiSelectPromptOptions<T>) -> AppResult
 where
     T: std::fmt::Display,
 {
+    if !is_interactive() {
+        let defaults = options.default_indices.unwrap_or_default();
+
+        return Ok(options
+            .options
+            .into_iter()
+            .enumerate()
+            .filter(|(idx, _)| defaults.contains(idx))
+            .map(|(_, option)| option)
+            .collect());
+    }
+
     Ok(MultiSelect::new(&options.title, options.options)
         .with_default(&options.default_indices.unwrap_or_default())
         .with_vim_mode(get_vim_mode()?)
@@ -160,8 +238,16 @@ where
 }
 
 fn get_vim_mode() -> AppResult<bool> {
-    let editor = env::var("VISUAL")
-        .or_else(|_| env::var("EDITOR"))
+    let settings = settings();
+    if let Some(vim_mode) = settings.prompt.vim_mode {
+        return Ok(vim_mode);
+    }
+
+    let editor = settings
+        .editor
+        .clone()
+        .or_else(|| env::var("VISUAL").ok())
+        .or_else(|| env::var("EDITOR").ok())
         .unwrap_or_default();
 
     if let Some(program) = editor.split_whitespace().next()
@@ -0,0 +1,209 @@
+use std::{
+    io::{Read, Write},
+    process::Child,
+    thread::JoinHandle,
+};
+
+use base64::{
+    Engine,
+    engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD},
+};
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
+
+pub const REDACTED: &[u8] = b"***";
+
+/// displayed instead of secret values by `envio show` and the TUI
+pub const MASKED: &str = "********";
+
+/// characters RFC 3986 escapes, everything but the unreserved `A-Z a-z 0-9 - _ . ~`
+const RFC3986: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+/// values shorter than this are not redacted, they would match almost everywhere
+pub const MIN_SECRET_LENGTH: usize = 4;
+
+/// base64 and URL-encoded variants of `value` that differ from it
+pub fn encoded_forms(value: &str) -> Vec<String> {
+    let mut forms: Vec<String> = labeled_encoded_forms(value)
+        .into_iter()
+        .map(|(_, form)| form)
+        .collect();
+
+    forms.sort();
+    forms.dedup();
+    forms
+}
+
+/// [`encoded_forms`] with the name of each encoding
+pub fn labeled_encoded_forms(value: &str) -> Vec<(&'static str, String)> {
+    let mut forms = vec![
+        ("base64", STANDARD.encode(value)),
+        ("base64", URL_SAFE_NO_PAD.encode(value)),
+        (
+            "url-encoded",
+            utf8_percent_encode(value, RFC3986).to_string(),
+        ),
+        // some encoders escape every character that isn't alphanumeric
+        (
+            "url-encoded",
+            utf8_percent_encode(value, NON_ALPHANUMERIC).to_string(),
+        ),
+    ];
+
+    forms.retain(|(_, form)| form != value);
+    forms.dedup_by(|a, b| a.1 == b.1);
+    forms
+}
+
+/// Replaces secrets in a byte stream with [`REDACTED`].
+///
+/// Input can be fed in arbitrary chunks: a trailing part of a chunk that could be
+/// the start of a secret is held back until the next chunk shows whether it is.
+#[derive(Clone)]
+pub struct Redactor {
+    secrets: Vec<Vec<u8>>,
+    first_bytes: [bool; 256],
+    pending: Vec<u8>,
+}
+
+impl Redactor {
+    pub fn new<I, S>(secrets: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut secrets: Vec<Vec<u8>> = secrets
+            .into_iter()
+            .map(|s| s.as_ref().as_bytes().to_vec())
+            .filter(|s| s.len() >= MIN_SECRET_LENGTH)
+            .collect();
+
+        // longest first so that a secret containing another one is fully replaced
+        secrets.sort_by_key(|s| std::cmp::Reverse(s.len()));
+        secrets.dedup();
+
+        let mut first_bytes = [false; 256];
+        for secret in &secrets {
+            first_bytes[secret[0] as usize] = true;
+        }
+
+        Self {
+            secrets,
+            first_bytes,
+            pending: Vec::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.secrets.is_empty()
+    }
+
+    /// redacts `chunk` into `out`, possibly holding back its last few bytes
+    pub fn push(&mut self, chunk: &[u8], out: &mut Vec<u8>) {
+        let mut buffer = std::mem::take(&mut self.pending);
+        buffer.extend_from_slice(chunk);
+
+        let consumed = self.redact(&buffer, out, false);
+        self.pending = buffer[consumed..].to_vec();
+    }
+
+    /// flushes everything held back by [`Redactor::push`]
+    pub fn finish(&mut self, out: &mut Vec<u8>) {
+        let buffer = std::mem::take(&mut self.pending);
+        self.redact(&buffer, out, true);
+    }
+
+    fn redact(&self, buffer: &[u8], out: &mut Vec<u8>, at_end: bool) -> usize {
+        let mut idx = 0;
+
+        while idx < buffer.len() {
+            if !self.first_bytes[buffer[idx] as usize] {
+                out.push(buffer[idx]);
+                idx += 1;
+                continue;
+            }
+
+            let rest = &buffer[idx..];
+
+            if !at_end
+                && self
+                    .secrets
+                    .iter()
+                    .any(|s| s.len() > rest.len() && s.starts_with(rest))
+            {
+                return idx;
+            }
+
+            match self.secrets.iter().find(|s| rest.starts_with(s)) {
+                Some(secret) => {
+                    out.extend_from_slice(REDACTED);
+                    idx += secret.len();
+                }
+                None => {
+                    out.push(buffer[idx]);
+                    idx += 1;
+                }
+            }
+        }
+
+        idx
+    }
+}
+
+/// copies the child's stdout and stderr to ours through a [`Redactor`]
+///
+/// The child must have been spawned with both streams piped.
+pub fn pipe_output(child: &mut Child, redactor: &Redactor) -> Vec<JoinHandle<()>> {
+    let mut handles = Vec::new();
+
+    if let Some(stdout) = child.stdout.take() {
+        let redactor = redactor.clone();
+        handles.push(std::thread::spawn(move || {
+            copy_redacted(stdout, std::io::stdout(), redactor)
+        }));
+    }
+
+    if let Some(stderr) = child.stderr.take() {
+        let redactor = redactor.clone();
+        handles.push(std::thread::spawn(move || {
+            copy_redacted(stderr, std::io::stderr(), redactor)
+        }));
+    }
+
+    handles
+}
+
+fn copy_redacted(mut reader: impl Read, mut writer: impl Write, mut redactor: Redactor) {
+    let mut buffer = [0u8; 8192];
+    let mut out = Vec::with_capacity(buffer.len());
+    let mut closed = false;
+
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(_) => break,
+        };
+
+        // keep draining once our side is closed so the child never blocks on a full pipe
+        if closed {
+            continue;
+        }
+
+        out.clear();
+        redactor.push(&buffer[..read], &mut out);
+
+        // flush on every read so the child's own buffering is preserved
+        closed = writer.write_all(&out).and_then(|_| writer.flush()).is_err();
+    }
+
+    if !closed {
+        out.clear();
+        redactor.finish(&mut out);
+        let _ = writer.write_all(&out).and_then(|_| writer.flush());
+    }
+}
@@ -0,0 +1,258 @@
+use std::path::PathBuf;
+
+use envio::{Env, EnvMap};
+use indexmap::IndexMap;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config,
+    error::{AppError, AppResult},
+};
+
+pub const SCHEMA_FILE: &str = "schema.toml";
+
+/// The variables a project expects, read from `.envio/schema.toml`.
+///
+/// The file is committed next to the profiles and never holds secrets, only
+/// names, descriptions, constraints and non secret defaults.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Schema {
+    #[serde(default)]
+    pub vars: IndexMap<String, VarSchema>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct VarSchema {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub required: bool,
+    #[serde(rename = "type")]
+    pub kind: VarType,
+    /// regex the whole value has to match
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    /// used when no profile sets the variable, also satisfies `required`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+}
+
+impl Default for VarSchema {
+    fn default() -> Self {
+        Self {
+            description: None,
+            required: true,
+            kind: VarType::String,
+            pattern: None,
+            default: None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VarType {
+    #[default]
+    String,
+    Integer,
+    Number,
+    Boolean,
+    Url,
+}
+
+impl VarType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::Integer => "integer",
+            Self::Number => "number",
+            Self::Boolean => "boolean",
+            Self::Url => "url",
+        }
+    }
+
+    fn accepts(self, value: &str) -> bool {
+        match self {
+            Self::String => true,
+            Self::Integer => value.parse::<i64>().is_ok(),
+            Self::Number => value.parse::<f64>().is_ok(),
+            Self::Boolean => matches!(
+                value.to_ascii_lowercase().as_str(),
+                "true" | "false" | "1" | "0" | "yes" | "no"
+            ),
+            Self::Url => url::Url::parse(value).is_ok(),
+        }
+    }
+
+    /// the narrowest type `value` fits, used by `envio schema generate`
+    pub fn infer(value: &str) -> Self {
+        [Self::Integer, Self::Number, Self::Boolean]
+            .into_iter()
+            .find(|kind| kind.accepts(value))
+            // `localhost:5432` parses as a url with the scheme `localhost`
+            .or_else(|| (value.contains("://") && Self::Url.accepts(value)).then_some(Self::Url))
+            .unwrap_or(Self::String)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ViolationKind {
+    Missing,
+    WrongType,
+    PatternMismatch,
+    /// set in the profile but not listed in the schema, only a warning
+    Unknown,
+}
+
+#[derive(Serialize)]
+pub struct Violation {
+    pub key: String,
+    pub kind: ViolationKind,
+    pub message: String,
+}
+
+pub fn schema_path() -> AppResult<PathBuf> {
+    Ok(config::get_envio_dir()?.join(SCHEMA_FILE))
+}
+
+/// the project's schema, `None` if there is no project or it has no schema
+pub fn load() -> AppResult<Option<Schema>> {
+    let Ok(path) = schema_path() else {
+        return Ok(None);
+    };
+
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let schema: Schema = toml::from_str(&std::fs::read_to_string(&path)?)
+        .map_err(|e| AppError::Config(format!("{}: {}", path.display(), e.message())))?;
+
+    for (key, var) in &schema.vars {
+        if let Some(pattern) = &var.pattern {
+            Regex::new(pattern).map_err(|e| {
+                AppError::Config(format!(
+                    "{}: invalid pattern for `{}`: {}",
+                    path.display(),
+                    key,
+                    e
+                ))
+            })?;
+        }
+    }
+
+    Ok(Some(schema))
+}
+
+impl Schema {
+    pub fn validate(&self, envs: &EnvMap) -> Vec<Violation> {
+        let mut violations = Vec::new();
+
+        for (key, var) in &self.vars {
+            let Some(env) = envs.get(key) else {
+                if var.required && var.default.is_none() {
+                    violations.push(Violation {
+                        key: key.clone(),
+                        kind: ViolationKind::Missing,
+                        message: "required but not set".to_string(),
+                    });
+                }
+                continue;
+            };
+
+            if !var.kind.accepts(&env.value) {
+                violations.push(Violation {
+                    key: key.clone(),
+                    kind: ViolationKind::WrongType,
+                    message: format!("is not a valid {}", var.kind.as_str()),
+                });
+            }
+
+            // validated when the schema was loaded
+            if let Some(pattern) = &var.pattern
+                && let Ok(regex) = Regex::new(&format!("^(?:{})$", pattern))
+                && !regex.is_match(&env.value)
+            {
+                violations.push(Violation {
+                    key: key.clone(),
+                    kind: ViolationKind::PatternMismatch,
+                    message: format!("does not match `{}`", pattern),
+                });
+            }
+        }
+
+        for key in envs.keys() {
+            if !self.vars.contains_key(key) {
+                violations.push(Violation {
+                    key: key.clone(),
+                    kind: ViolationKind::Unknown,
+                    message: "not in the schema".to_string(),
+                });
+            }
+        }
+
+        violations
+    }
+
+    /// the schema defaults as variables, so they can be filtered like profile ones
+    pub fn defaults(&self) -> EnvMap {
+        self.vars
+            .iter()
+            .filter_map(|(key, var)| {
+                var.default
+                    .as_ref()
+                    .map(|default| Env::from_key_value(key.clone(), default.clone()))
+            })
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    /// a schema listing every variable of `envs` as required
+    pub fn generate(envs: &EnvMap) -> Self {
+        let vars = envs
+            .iter()
+            .map(|env| {
+                (
+                    env.key.clone(),
+                    VarSchema {
+                        description: env.comment.clone(),
+                        kind: VarType::infer(&env.value),
+                        ..Default::default()
+                    },
+                )
+            })
+            .collect();
+
+        Self { vars }
+    }
+
+    /// `.env.example` contents, descriptions as comments and only the defaults as values
+    pub fn example(&self) -> String {
+        let mut output = String::new();
+
+        for (key, var) in &self.vars {
+            if let Some(description) = &var.description {
+                for line in description.lines() {
+                    output += &format!("# {}\n", line);
+                }
+            }
+
+            let mut notes = vec![if var.required { "required" } else { "optional" }];
+            if var.kind != VarType::String {
+                notes.push(var.kind.as_str());
+            }
+            output += &format!("# {}\n", notes.join(", "));
+
+            if let Some(pattern) = &var.pattern {
+                output += &format!("# must match: {}\n", pattern);
+            }
+
+            output += &format!("{}={}\n\n", key, var.default.as_deref().unwrap_or(""));
+        }
+
+        output.trim_end().to_string() + "\n"
+    }
+}
@@ -0,0 +1,549 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use envio::cipher::CipherKind;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use toml::{Table, Value};
+
+use crate::{
+    config,
+    error::{AppError, AppResult},
+};
+
+pub const CONFIG_FILE: &str = "config.toml";
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Kind {
+    Bool,
+    Integer,
+    String,
+    List,
+}
+
+/// a setting that can be read with `envio config get`, see [`KEYS`]
+pub struct Key {
+    pub name: &'static str,
+    pub kind: Kind,
+    pub description: &'static str,
+    /// not read from project configs, a cloned repo must not be able to set these
+    pub user_only: bool,
+}
+
+impl Key {
+    /// environment variable overriding the setting, `run.clean` -> `ENVIO_RUN_CLEAN`
+    pub fn env_var(&self) -> String {
+        format!("ENVIO_{}", self.name.replace('.', "_").to_ascii_uppercase())
+    }
+}
+
+macro_rules! key {
+    ($name:expr, $kind:ident, $description:expr) => {
+        Key {
+            name: $name,
+            kind: Kind::$kind,
+            description: $description,
+            user_only: false,
+        }
+    };
+    ($name:expr, $kind:ident, $description:expr, user_only) => {
+        Key {
+            name: $name,
+            kind: Kind::$kind,
+            description: $description,
+            user_only: true,
+        }
+    };
+}
+
+pub const KEYS: &[Key] = &[
+    key!(
+        "editor",
+        String,
+        "editor used by `envio edit` (default: $EDITOR)",
+        user_only
+    ),
+    key!("updates.check", Bool, "check for new versions of envio"),
+    key!(
+        "prompt.vim_mode",
+        Bool,
+        "use vim keys in prompts (default: on if the editor is vim)"
+    ),
+    key!("prompt.no_input", Bool, "never prompt, as with --no-input"),
+    key!("create.cipher", String, "cipher used by `envio create`"),
+    key!(
+        "create.keyring",
+        String,
+        "store new keys in the keyring (ask, always, never)"
+    ),
+    key!("create.comments", Bool, "prompt for comments when creating"),
+    key!(
+        "create.expires",
+        Bool,
+        "prompt for expiration dates when creating"
+    ),
+    key!(
+        "export.format",
+        String,
+        "format used by `envio export` (dotenv, json, yaml, shell)"
+    ),
+    key!("export.output", String, "file written by `envio export`"),
+    key!(
+        "run.clean",
+        Bool,
+        "start `envio run` from a clean environment"
+    ),
+    key!(
+        "run.keep",
+        List,
+        "parent variables kept by `envio run --clean`"
+    ),
+    key!(
+        "run.redact",
+        Bool,
+        "redact profile values in `envio run` output"
+    ),
+    key!("lint.disable", List, "rules skipped by `envio lint`"),
+    key!(
+        "lint.max_value_size",
+        Integer,
+        "values longer than this many bytes are reported by `envio lint`"
+    ),
+    key!(
+        "lint.strict",
+        Bool,
+        "make `envio lint` fail on warnings too"
+    ),
+    key!(
+        "secrets.unencrypted",
+        String,
+        "what to do with secrets going into unencrypted profiles (warn, refuse, ignore)"
+    ),
+    key!(
+        "storage.backend",
+        String,
+        "where profiles are stored (file, sqlite), sqlite needs envio built with the `sqlite` feature"
+    ),
+];
+
+pub fn find_key(name: &str) -> AppResult<&'static Key> {
+    KEYS.iter().find(|key| key.name == name).ok_or_else(|| {
+        AppError::Msg(format!(
+            "Unknown setting `{}`, run `envio config list` to see all settings",
+            name
+        ))
+    })
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Settings {
+    pub editor: Option<String>,
+    pub updates: UpdateSettings,
+    pub prompt: PromptSettings,
+    pub create: CreateSettings,
+    pub export: ExportSettings,
+    pub run: RunSettings,
+    pub lint: LintSettings,
+    pub secrets: SecretSettings,
+    pub storage: StorageSettings,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct UpdateSettings {
+    pub check: bool,
+}
+
+impl Default for UpdateSettings {
+    fn default() -> Self {
+        Self { check: true }
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PromptSettings {
+    pub vim_mode: Option<bool>,
+    pub no_input: bool,
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CreateSettings {
+    pub cipher: Option<CipherKind>,
+    pub keyring: KeyringMode,
+    pub comments: bool,
+    pub expires: bool,
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyringMode {
+    #[default]
+    Ask,
+    Always,
+    Never,
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ExportSettings {
+    pub format: Option<ExportFormat>,
+    pub output: Option<String>,
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Dotenv,
+    Json,
+    Yaml,
+    Shell,
+}
+
+impl ExportFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Dotenv => "dotenv",
+            Self::Json => "json",
+            Self::Yaml => "yaml",
+            Self::Shell => "shell",
+        }
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RunSettings {
+    pub clean: bool,
+    pub keep: Vec<String>,
+    pub redact: bool,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LintSettings {
+    pub disable: Vec<String>,
+    pub max_value_size: usize,
+    pub strict: bool,
+}
+
+impl Default for LintSettings {
+    fn default() -> Self {
+        Self {
+            disable: Vec::new(),
+            max_value_size: envio::lint::DEFAULT_MAX_VALUE_SIZE,
+            strict: false,
+        }
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SecretSettings {
+    pub unencrypted: SecretPolicy,
+}
+
+/// what happens when a value that looks like a secret goes into a profile
+/// without encryption
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SecretPolicy {
+    #[default]
+    Warn,
+    Refuse,
+    Ignore,
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StorageSettings {
+    pub backend: StorageBackend,
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageBackend {
+    /// one `.envio` file per profile in the profiles directory
+    #[default]
+    File,
+    /// `profiles.db` next to the profiles directory, needs the `sqlite` feature
+    Sqlite,
+}
+
+impl StorageBackend {
+    pub fn parse(backend: &str) -> AppResult<Self> {
+        match backend {
+            "file" => Ok(Self::File),
+            "sqlite" => Ok(Self::Sqlite),
+            _ => Err(AppError::Msg(format!(
+                "Unsupported storage backend: {}",
+                backend
+            ))),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::File => "file",
+            Self::Sqlite => "sqlite",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Source {
+    Default,
+    Project,
+    User,
+    Env,
+}
+
+impl std::fmt::Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Default => write!(f, "default"),
+            Source::Project => write!(f, "project"),
+            Source::User => write!(f, "user"),
+            Source::Env => write!(f, "env"),
+        }
+    }
+}
+
+/// Settings merged from every layer, lowest priority first: the project's
+/// `.envio/config.toml`, the user config and `ENVIO_*` environment variables.
+/// Command line flags are applied on top by each command.
+pub struct LoadedSettings {
+    pub settings: Settings,
+    /// the layer each set key came from
+    pub sources: IndexMap<&'static str, Source>,
+}
+
+static SETTINGS: OnceLock<LoadedSettings> = OnceLock::new();
+
+/// loads and validates the settings, must be called after [`config::set_overrides`]
+pub fn init() -> AppResult<()> {
+    let loaded = load()?;
+    let _ = SETTINGS.set(loaded);
+    Ok(())
+}
+
+pub fn settings() -> &'static Settings {
+    &SETTINGS
+        .get_or_init(|| LoadedSettings {
+            settings: Settings::default(),
+            sources: IndexMap::new(),
+        })
+        .settings
+}
+
+/// the layer the loaded value of `key` came from
+pub fn source(key: &str) -> Source {
+    SETTINGS
+        .get()
+        .and_then(|loaded| loaded.sources.get(key).copied())
+        .unwrap_or(Source::Default)
+}
+
+pub fn project_config_path() -> Option<PathBuf> {
+    config::get_envio_dir()
+        .ok()
+        .map(|dir| dir.join(CONFIG_FILE))
+}
+
+pub fn user_config_path() -> PathBuf {
+    config::get_global_dir().join(CONFIG_FILE)
+}
+
+pub fn load() -> AppResult<LoadedSettings> {
+    let mut merged = Table::new();
+    let mut sources = IndexMap::new();
+
+    let files = [
+        (project_config_path(), Source::Project),
+        (Some(user_config_path()), Source::User),
+    ];
+
+    for (path, source) in files {
+        let Some(path) = path else {
+            continue;
+        };
+
+        let table = read_table(&path)?;
+
+        for (key, value) in flatten(&table) {
+            let key = find_key(&key).map_err(|e| invalid(&path, e))?;
+
+            if key.user_only && source == Source::Project {
+                return Err(invalid(
+                    &path,
+                    format!("`{}` can only be set in the user config", key.name),
+                ));
+            }
+
+            set_value(&mut merged, key.name, value);
+            sources.insert(key.name, source);
+        }
+    }
+
+    for key in KEYS {
+        let var = key.env_var();
+        if let Ok(raw) = std::env::var(&var) {
+            let value =
+                parse_value(key, &raw).map_err(|e| AppError::Config(format!("{}: {}", var, e)))?;
+            set_value(&mut merged, key.name, value);
+            sources.insert(key.name, Source::Env);
+        }
+    }
+
+    let settings =
+        Settings::deserialize(merged).map_err(|e| AppError::Config(e.message().to_string()))?;
+
+    Ok(LoadedSettings { settings, sources })
+}
+
+/// `storage.backend` of the envio folder `envio_dir`
+///
+/// It is read from the config next to the profiles rather than the merged
+/// settings, so a project and the global store can use different backends.
+/// `ENVIO_STORAGE_BACKEND` still applies to both.
+pub fn storage_backend(envio_dir: &Path) -> AppResult<StorageBackend> {
+    let key = find_key("storage.backend")?;
+    if let Ok(raw) = std::env::var(key.env_var()) {
+        return StorageBackend::parse(&raw);
+    }
+
+    let path = envio_dir.join(CONFIG_FILE);
+    let settings = Settings::deserialize(read_table(&path)?).map_err(|e| invalid(&path, e))?;
+
+    Ok(settings.storage.backend)
+}
+
+/// reads a config file and checks it on its own, so errors point at the file
+pub fn read_table(path: &Path) -> AppResult<Table> {
+    if !path.exists() {
+        return Ok(Table::new());
+    }
+
+    let contents = std::fs::read_to_string(path)?;
+    let table: Table = toml::from_str(&contents).map_err(|e| invalid(path, e))?;
+    validate(&table).map_err(|e| invalid(path, e))?;
+
+    Ok(table)
+}
+
+pub fn validate(table: &Table) -> Result<(), String> {
+    Settings::deserialize(table.clone())
+        .map(|_| ())
+        .map_err(|e| e.message().to_string())
+}
+
+fn invalid(path: &Path, e: impl std::fmt::Display) -> AppError {
+    AppError::Config(format!("{}: {}", path.display(), e))
+}
+
+/// parses a value given on the command line or in an environment variable
+pub fn parse_value(key: &Key, raw: &str) -> Result<Value, String> {
+    match key.kind {
+        Kind::Bool => match raw.to_ascii_lowercase().as_str() {
+            "true" | "1" | "yes" | "on" => Ok(Value::Boolean(true)),
+            "false" | "0" | "no" | "off" => Ok(Value::Boolean(false)),
+            _ => Err(format!("expected true or false, found `{}`", raw)),
+        },
+        Kind::Integer => raw
+            .parse()
+            .map(Value::Integer)
+            .map_err(|_| format!("expected a number, found `{}`", raw)),
+        Kind::String => Ok(Value::String(raw.to_string())),
+        Kind::List => Ok(Value::Array(
+            raw.split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(|item| Value::String(item.to_string()))
+                .collect(),
+        )),
+    }
+}
+
+/// `{ run = { clean = true } }` -> `[("run.clean", true)]`
+pub fn flatten(table: &Table) -> Vec<(String, Value)> {
+    let mut entries = Vec::new();
+
+    for (key, value) in table {
+        match value {
+            Value::Table(inner) => entries.extend(
+                flatten(inner)
+                    .into_iter()
+                    .map(|(inner_key, value)| (format!("{}.{}", key, inner_key), value)),
+            ),
+            _ => entries.push((key.clone(), value.clone())),
+        }
+    }
+
+    entries
+}
+
+pub fn get_value(table: &Table, name: &str) -> Option<Value> {
+    let (section, key) = match name.split_once('.') {
+        Some((section, key)) => (Some(section), key),
+        None => (None, name),
+    };
+
+    let table = match section {
+        Some(section) => table.get(section)?.as_table()?,
+        None => table,
+    };
+
+    table.get(key).cloned()
+}
+
+pub fn set_value(table: &mut Table, name: &str, value: Value) {
+    match name.split_once('.') {
+        Some((section, key)) => {
+            let section = table
+                .entry(section)
+                .or_insert_with(|| Value::Table(Table::new()));
+
+            if let Value::Table(section) = section {
+                section.insert(key.to_string(), value);
+            }
+        }
+        None => {
+            table.insert(name.to_string(), value);
+        }
+    }
+}
+
+/// returns whether the key was set
+pub fn remove_value(table: &mut Table, name: &str) -> bool {
+    match name.split_once('.') {
+        Some((section, key)) => {
+            let Some(Value::Table(inner)) = table.get_mut(section) else {
+                return false;
+            };
+
+            let removed = inner.remove(key).is_some();
+            if inner.is_empty() {
+                table.remove(section);
+            }
+            removed
+        }
+        None => table.remove(name).is_some(),
+    }
+}
+
+/// human readable form of a value for `envio config get` and `list`
+pub fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Array(items) => items
+            .iter()
+            .map(display_value)
+            .collect::<Vec<_>>()
+            .join(","),
+        other => other.to_string(),
+    }
+}
@@ -14,8 +14,8 @@ use super::{
     screens::{Action, Screen, ScreenEvent, ScreenId, SelectScreen},
 };
 use crate::{
-    config::{get_profile_metadata, get_profile_path},
-    error::AppResult,
+    config::{get_profile_metadata, open_profile},
+    error::{AppError, AppResult},
 };
 
 pub struct TuiApp {
@@ -125,15 +125,19 @@ impl TuiApp {
                 if let Ok(entry) = keyring::Entry::new("envio", metadata.uuid.as_str())
                     && let Ok(pwd) = entry.get_password()
                 {
-                    let path = get_profile_path(name)?;
-                    if let Ok(profile) = envio::get_profile(
-                        path,
+                    match open_profile(
+                        name,
                         Some(|_: &envio::ProfileMetadata| {
                             Ok::<Zeroizing<String>, envio::error::Error>(pwd.into())
                         }),
                     ) {
-                        self.navigation.push(ScreenId::Edit(Box::new(profile)))?;
-                        return Ok(());
+                        Ok(profile) => {
+                            self.navigation.push(ScreenId::Edit(Box::new(profile)))?;
+                            return Ok(());
+                        }
+                        // the stored key is stale, ask for the right one
+                        Err(AppError::Library(envio::error::Error::WrongKey)) => {}
+                        Err(e) => return Err(e),
                     }
                 }
 
@@ -147,9 +151,8 @@ impl TuiApp {
     }
 
     fn open_unencrypted_profile(&mut self, name: &str) -> AppResult<()> {
-        let path = get_profile_path(name)?;
-        let profile = envio::get_profile(
-            path,
+        let profile = open_profile(
+            name,
             None::<fn(&envio::ProfileMetadata) -> Result<Zeroizing<String>, envio::error::Error>>,
         )?;
 
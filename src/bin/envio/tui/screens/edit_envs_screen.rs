@@ -1,4 +1,8 @@
-use envio::{Env, Profile};
+use envio::{
+    Env, EnvMap, Profile, Sensitivity,
+    cipher::CipherKind,
+    secrets::{self, Confidence, Detection},
+};
 use ratatui::{
     Frame,
     crossterm::event::{KeyCode, KeyEvent, KeyModifiers},
@@ -7,10 +11,20 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
 };
-use std::thread::{self, JoinHandle};
+use std::{
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
 
 use super::{Action, Screen, ScreenEvent, ScreenId};
-use crate::error::AppResult;
+use crate::{
+    error::{AppError, AppResult},
+    redact,
+    settings::{SecretPolicy, settings},
+};
+
+/// how long secret values stay revealed before they are masked again
+const REVEAL_TIMEOUT: Duration = Duration::from_secs(10);
 
 enum EditMode {
     None,
@@ -33,6 +47,10 @@ pub struct EditEnvsScreen {
     edit_buffer: String,
     status: Status,
     save_handle: Option<JoinHandle<AppResult<()>>>,
+    /// shown once the save finished
+    warning: Option<String>,
+    /// secret values are displayed until then
+    revealed_until: Option<Instant>,
 }
 
 impl Screen for EditEnvsScreen {
@@ -123,6 +141,17 @@ impl Screen for EditEnvsScreen {
                     self.delete_current();
                 }
 
+                KeyCode::Char('r') => {
+                    self.revealed_until = match self.revealed_until {
+                        Some(_) => None,
+                        None => Some(Instant::now() + REVEAL_TIMEOUT),
+                    };
+                }
+
+                KeyCode::Char('m') => {
+                    self.toggle_secret();
+                }
+
                 KeyCode::Char('s')
                     if (key.modifiers.contains(KeyModifiers::CONTROL)
                         || key.modifiers.is_empty()) =>
@@ -139,6 +168,14 @@ impl Screen for EditEnvsScreen {
 
     fn tick(&mut self) -> AppResult<Option<ScreenEvent>> {
         self.check_save();
+
+        if self
+            .revealed_until
+            .is_some_and(|until| Instant::now() >= until)
+        {
+            self.revealed_until = None;
+        }
+
         Ok(None)
     }
 
@@ -163,6 +200,8 @@ impl EditEnvsScreen {
             edit_buffer: String::new(),
             status: Status::Idle,
             save_handle: None,
+            warning: None,
+            revealed_until: None,
         })
     }
 
@@ -286,8 +325,53 @@ impl EditEnvsScreen {
         }
     }
 
+    /// marks the selected variable as secret, or as plain when it already is
+    fn toggle_secret(&mut self) {
+        if let Some(idx) = self.get_selected_index()
+            && let Some(env) = self.envs.get_mut(idx)
+        {
+            env.sensitivity = if env.is_secret() {
+                Sensitivity::Plain
+            } else {
+                Sensitivity::Secret
+            };
+        }
+    }
+
     fn save_changes(&mut self) -> AppResult<()> {
-        self.profile.envs = self.envs.clone().into();
+        let envs: EnvMap = self
+            .envs
+            .iter()
+            .map(|env| {
+                let mut env = env.clone();
+                env.touch(self.profile.envs.get(&env.key));
+                env
+            })
+            .collect::<Vec<_>>()
+            .into();
+        let detections = self.unencrypted_secrets(&envs);
+
+        if !detections.is_empty() && settings().secrets.unencrypted == SecretPolicy::Refuse {
+            self.status = Status::Error(
+                AppError::UnencryptedSecrets(detections.len()).to_string(),
+                Color::Red,
+            );
+            return Ok(());
+        }
+
+        self.warning = match detections.as_slice() {
+            [] => None,
+            [detection] => Some(format!(
+                "Saved, but '{}' looks like a secret ({}) and the profile is not encrypted",
+                detection.key, detection.kind
+            )),
+            _ => Some(format!(
+                "Saved, but {} values look like secrets and the profile is not encrypted",
+                detections.len()
+            )),
+        };
+
+        self.profile.envs = envs;
 
         let mut profile = self.profile.clone();
 
@@ -300,10 +384,30 @@ impl EditEnvsScreen {
         Ok(())
     }
 
+    /// the secrets of `envs` that would be saved without encryption, none when
+    /// `secrets.unencrypted` is `ignore`
+    fn unencrypted_secrets(&self, envs: &EnvMap) -> Vec<Detection> {
+        if self.profile.metadata.cipher_kind != CipherKind::NONE
+            || settings().secrets.unencrypted == SecretPolicy::Ignore
+        {
+            return Vec::new();
+        }
+
+        secrets::detect(envs)
+            .into_iter()
+            .filter(|detection| detection.confidence == Confidence::High)
+            .collect()
+    }
+
     fn check_save(&mut self) {
         if let Some(handle) = self.save_handle.take() {
             match handle.join() {
-                Ok(Ok(())) => self.status = Status::Saved,
+                Ok(Ok(())) => {
+                    self.status = match self.warning.take() {
+                        Some(warning) => Status::Error(warning, Color::Yellow),
+                        None => Status::Saved,
+                    }
+                }
                 Ok(Err(e)) => self.status = Status::Error(e.to_string(), Color::Red),
                 Err(_) => {
                     self.status = Status::Error("Save thread panicked".to_string(), Color::Red)
@@ -369,6 +473,8 @@ impl EditEnvsScreen {
 
                 let value_display = if is_editing_value {
                     self.edit_buffer.as_str()
+                } else if env.is_secret() && self.revealed_until.is_none() {
+                    redact::MASKED
                 } else {
                     &env.value
                 };
@@ -450,7 +556,7 @@ impl EditEnvsScreen {
                 "Editing value: Type to edit | Enter: Finish | Esc: Cancel | Left/Right: Switch"
             }
             _ => {
-                "↑↓: Navigate | Enter: Edit key | →: Edit value | a: Add | d: Delete | s: Save | Esc: Back"
+                "↑↓: Navigate | Enter: Edit key | →: Edit value | a: Add | d: Delete | m: Mark secret | r: Reveal | s: Save | Esc: Back"
             }
         };
 
@@ -11,7 +11,7 @@ use std::thread::{self, JoinHandle};
 use zeroize::Zeroizing;
 
 use super::{Action, Screen, ScreenEvent, ScreenId};
-use crate::{config::get_profile_path, error::AppResult};
+use crate::{config::open_profile, error::AppResult};
 
 enum Feedback {
     Decrypting,
@@ -23,7 +23,7 @@ pub struct GetKeyScreen {
     profile_name: String,
     key: Zeroizing<String>,
     feedback: Option<Feedback>,
-    decrypt_handle: Option<JoinHandle<Option<Profile>>>,
+    decrypt_handle: Option<JoinHandle<Result<Profile, envio::error::Error>>>,
 }
 
 impl Screen for GetKeyScreen {
@@ -162,20 +162,13 @@ impl GetKeyScreen {
         let key = self.key.clone();
 
         self.decrypt_handle = Some(thread::spawn(move || {
-            let profile_path = match get_profile_path(&profile_name) {
-                Ok(p) => p,
-                Err(_) => {
-                    return None;
-                }
-            };
-
-            envio::get_profile(
-                profile_path,
+            open_profile(
+                &profile_name,
                 Some(|_: &envio::ProfileMetadata| {
                     Ok::<Zeroizing<String>, envio::error::Error>(key)
                 }),
             )
-            .ok()
+            .map_err(envio::error::Error::from)
         }));
 
         Ok(())
@@ -184,15 +177,31 @@ impl GetKeyScreen {
     fn check_decrypt(&mut self) -> Option<ScreenEvent> {
         if let Some(handle) = self.decrypt_handle.take() {
             match handle.join() {
-                Ok(Some(profile)) => {
+                Ok(Ok(profile)) => {
                     self.feedback = None;
                     return Some(ScreenEvent::ProfileDecrypted(profile));
                 }
 
-                Ok(None) => {
+                Ok(Err(envio::error::Error::WrongKey)) => {
                     self.feedback = Some(Feedback::IncorrectKey);
                 }
 
+                Ok(Err(envio::error::Error::Corrupted(_))) => {
+                    self.feedback = Some(Feedback::DecryptionFailed(
+                        "The profile file is damaged".to_string(),
+                    ));
+                }
+
+                Ok(Err(envio::error::Error::UnsupportedVersion { .. })) => {
+                    self.feedback = Some(Feedback::DecryptionFailed(
+                        "The profile needs a newer version of envio".to_string(),
+                    ));
+                }
+
+                Ok(Err(e)) => {
+                    self.feedback = Some(Feedback::DecryptionFailed(e.to_string()));
+                }
+
                 Err(_) => {
                     self.feedback = Some(Feedback::DecryptionFailed(
                         "Decryption thread panicked".to_string(),
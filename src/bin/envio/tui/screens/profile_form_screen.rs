@@ -16,7 +16,7 @@ use zeroize::Zeroizing;
 
 use super::{Action, Screen, ScreenEvent, ScreenId};
 use crate::{
-    config::{build_profile_path, get_profile_metadata, get_profile_path},
+    config::{get_profile_metadata, locate_profile, require_profile},
     error::AppResult,
     profile_ops,
 };
@@ -330,10 +330,8 @@ impl Screen for CreateProfileScreen {
                 }
 
                 KeyCode::Char(c) => match self.current_field {
-                    CreateField::Name => {
-                        if c != ' ' {
-                            self.name.push(c);
-                        }
+                    CreateField::Name if c != ' ' => {
+                        self.name.push(c);
                     }
 
                     CreateField::Description => self.description.push(c),
@@ -974,17 +972,16 @@ impl EditProfileScreen {
             Some(self.description.trim().to_string())
         };
 
-        let new_file_path = build_profile_path(&new_profile_name)?;
-        let old_file_path = get_profile_path(&self.profile_name)?;
+        require_profile(&self.profile_name)?;
+        let (store, name) = locate_profile(&self.profile_name)?;
 
-        let mut serialized_profile = envio::utils::get_serialized_profile(&old_file_path)?;
-
-        serialized_profile.metadata.name = new_profile_name;
+        let mut serialized_profile = store.load(name)?;
         serialized_profile.metadata.description = new_profile_description;
+        store.save(name, &serialized_profile)?;
 
-        envio::utils::save_serialized_profile(&old_file_path, serialized_profile)?;
-
-        std::fs::rename(old_file_path, new_file_path)?;
+        if new_profile_name != name {
+            store.rename(name, &new_profile_name)?;
+        }
 
         self.status = Status::Saved;
 
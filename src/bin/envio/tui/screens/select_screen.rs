@@ -9,10 +9,7 @@ use ratatui::{
 };
 
 use super::{Action, Screen, ScreenId};
-use crate::{
-    config::{get_profile_dir, get_profile_metadata},
-    error::AppResult,
-};
+use crate::{config::collect_profile_metadata, error::AppResult};
 
 fn styled_span(content: impl Into<String>, fg: Color, bold: bool) -> Span<'static> {
     let mut style = Style::default().fg(fg);
@@ -138,29 +135,10 @@ impl SelectScreen {
 
     pub fn load_profiles(&mut self) -> AppResult<()> {
         self.profiles.clear();
-        let profile_dir = get_profile_dir()?;
-
-        if !profile_dir.exists() {
-            return Ok(());
-        }
-
-        for entry in std::fs::read_dir(profile_dir)? {
-            let entry = entry?;
-            let path = entry.path();
-
-            if path.extension().and_then(|s| s.to_str()) != Some("envio") {
-                continue;
-            }
-
-            let profile_name = match path.file_stem().and_then(|s| s.to_str()) {
-                Some(name) if !name.starts_with('.') => name.to_string(),
-                _ => continue,
-            };
-
-            let metadata = get_profile_metadata(&profile_name)?;
 
+        for metadata in collect_profile_metadata()? {
             self.profiles.push(ProfileInfo {
-                name: profile_name,
+                name: metadata.name.clone(),
                 metadata,
             });
         }
@@ -1,4 +1,4 @@
-use std::{fs::File, io::Write, path::PathBuf};
+use std::path::PathBuf;
 
 use envio::{Env, EnvMap};
 use indicatif::{ProgressBar, ProgressState, ProgressStyle};
@@ -28,7 +28,7 @@ pub fn parse_envs_from_string(buffer: &str) -> AppResult<EnvMap> {
     Ok(envs_vec)
 }
 
-pub async fn download_file(url: &str, path: impl AsRef<std::path::Path>) -> AppResult<()> {
+pub async fn download(url: &str) -> AppResult<Vec<u8>> {
     let client = Client::new();
 
     let pb = ProgressBar::new_spinner();
@@ -41,7 +41,6 @@ pub async fn download_file(url: &str, path: impl AsRef<std::path::Path>) -> AppR
     );
 
     let mut resp = client.get(url).send().await?;
-    let mut file = File::create(path)?;
 
     let content_length = resp
         .content_length()
@@ -60,15 +59,14 @@ pub async fn download_file(url: &str, path: impl AsRef<std::path::Path>) -> AppR
         .progress_chars("#>-");
     pb.set_style(style);
 
-    let mut downloaded = 0u64;
+    let mut content = Vec::with_capacity(content_length as usize);
     while let Some(chunk) = resp.chunk().await? {
-        file.write_all(&chunk)?;
-        downloaded += chunk.len() as u64;
-        pb.set_position(downloaded);
+        content.extend_from_slice(&chunk);
+        pb.set_position(content.len() as u64);
     }
 
     pb.finish();
-    Ok(())
+    Ok(content)
 }
 
 pub fn get_cwd() -> PathBuf {
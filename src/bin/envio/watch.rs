@@ -0,0 +1,140 @@
+use std::{collections::HashMap, time::Duration};
+
+use envio::{Profile, profile::ProfileMetadata};
+use indexmap::IndexMap;
+use zeroize::Zeroizing;
+
+use crate::{config, error::AppResult, profile_ops};
+
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+type Snapshot = Vec<Option<String>>;
+
+/// Detects changes to a set of profiles by polling what their store holds.
+///
+/// A change is only reported once the profiles have stayed the same for a full poll,
+/// so a profile that is still being written is not read half way through.
+pub struct ProfileWatcher {
+    profile_names: Vec<String>,
+    last_seen: Snapshot,
+    last_reported: Snapshot,
+}
+
+impl ProfileWatcher {
+    pub fn new(profile_names: Vec<String>) -> Self {
+        let snapshot = snapshot(&profile_names);
+
+        Self {
+            profile_names,
+            last_seen: snapshot.clone(),
+            last_reported: snapshot,
+        }
+    }
+
+    pub fn poll(&mut self) -> bool {
+        let current = snapshot(&self.profile_names);
+
+        if current != self.last_seen {
+            self.last_seen = current;
+            return false;
+        }
+
+        if current != self.last_reported {
+            self.last_reported = current;
+            return true;
+        }
+
+        false
+    }
+}
+
+/// the stored form of each profile, `None` while it can't be read
+fn snapshot(profile_names: &[String]) -> Snapshot {
+    profile_names
+        .iter()
+        .map(|profile_name| {
+            let (store, name) = config::locate_profile(profile_name).ok()?;
+            serde_json::to_string(&store.load(name).ok()?).ok()
+        })
+        .collect()
+}
+
+/// Remembers the key used for each profile so it can be decrypted again without prompting.
+#[derive(Default)]
+pub struct KeyCache {
+    keys: HashMap<String, Zeroizing<String>>,
+}
+
+impl KeyCache {
+    pub fn get_profiles(&mut self, profile_names: &[String]) -> AppResult<Vec<Profile>> {
+        profile_names
+            .iter()
+            .map(|name| self.get_profile(name))
+            .collect()
+    }
+
+    fn get_profile(&mut self, profile_name: &str) -> AppResult<Profile> {
+        config::open_profile(
+            profile_name,
+            Some(|meta: &ProfileMetadata| {
+                if let Some(key) = self.keys.get(&meta.uuid) {
+                    return Ok(key.clone());
+                }
+
+                let key = profile_ops::resolve_key(meta)?;
+                self.keys.insert(meta.uuid.clone(), key.clone());
+                Ok(key)
+            }),
+        )
+    }
+}
+
+/// keys that were added, removed or changed between two environments
+#[derive(Default)]
+pub struct EnvDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<String>,
+}
+
+impl EnvDiff {
+    pub fn between(old: &IndexMap<String, String>, new: &IndexMap<String, String>) -> Self {
+        let mut diff = Self::default();
+
+        for (key, value) in new {
+            match old.get(key) {
+                None => diff.added.push(key.clone()),
+                Some(old_value) if old_value != value => diff.changed.push(key.clone()),
+                _ => {}
+            }
+        }
+
+        diff.removed = old
+            .keys()
+            .filter(|key| !new.contains_key(*key))
+            .cloned()
+            .collect();
+
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl std::fmt::Display for EnvDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let parts: Vec<String> = [
+            ("added", &self.added),
+            ("removed", &self.removed),
+            ("changed", &self.changed),
+        ]
+        .into_iter()
+        .filter(|(_, keys)| !keys.is_empty())
+        .map(|(label, keys)| format!("{}: {}", label, keys.join(", ")))
+        .collect();
+
+        write!(f, "{}", parts.join("; "))
+    }
+}
@@ -13,10 +13,17 @@ use crate::{
 pub fn check_gpg() -> Result<()> {
     match Command::new("gpg").arg("--version").output() {
         Ok(_) => Ok(()),
-        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Err(Error::Cipher(
-            "gpg not found, please install it on your system".to_string(),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Err(gpg_error(
+            "not found, please install it on your system".to_string(),
         )),
-        Err(e) => Err(Error::Cipher(format!("failed to probe gpg: {e}"))),
+        Err(e) => Err(gpg_error(format!("failed to probe gpg: {e}"))),
+    }
+}
+
+fn gpg_error(message: String) -> Error {
+    Error::ExternalTool {
+        tool: "gpg".to_string(),
+        message,
     }
 }
 
@@ -64,7 +71,7 @@ impl Cipher for GPG {
             .stdout(Stdio::piped())
             .stderr(Stdio::null())
             .spawn()
-            .map_err(|e| Error::Cipher(format!("failed to spawn gpg: {e}")))?;
+            .map_err(|e| gpg_error(format!("failed to spawn gpg: {e}")))?;
 
         let stdin = match gpg_process.stdin.as_mut() {
             Some(stdin) => stdin,
@@ -78,8 +85,8 @@ impl Cipher for GPG {
         let output = gpg_process.wait_with_output()?;
 
         if !output.status.success() {
-            return Err(Error::Cipher(format!(
-                "gpg encrypt failed (exit {})",
+            return Err(gpg_error(format!(
+                "encrypt failed (exit {})",
                 output.status
             )));
         }
@@ -98,7 +105,7 @@ impl Cipher for GPG {
             .stdout(Stdio::piped())
             .stderr(Stdio::null())
             .spawn()
-            .map_err(|e| Error::Cipher(format!("failed to spawn gpg: {e}")))?;
+            .map_err(|e| gpg_error(format!("failed to spawn gpg: {e}")))?;
 
         let stdin = match gpg_process.stdin.as_mut() {
             Some(stdin) => stdin,
@@ -112,13 +119,13 @@ impl Cipher for GPG {
         let output = gpg_process.wait_with_output()?;
 
         if !output.status.success() {
-            return Err(Error::Cipher(format!(
-                "gpg decrypt failed (exit {})",
+            return Err(gpg_error(format!(
+                "decrypt failed (exit {})",
                 output.status
             )));
         }
 
-        Ok(output.stdout.into())
+        EnvMap::try_from(output.stdout)
     }
 
     fn export_metadata(&self) -> Option<serde_json::Value> {
@@ -126,7 +133,8 @@ impl Cipher for GPG {
     }
 
     fn import_metadata(&mut self, data: serde_json::Value) -> Result<()> {
-        self.metadata = serde_json::from_value(data)?;
+        self.metadata =
+            serde_json::from_value(data).map_err(|e| Error::Corrupted(e.to_string()))?;
 
         Ok(())
     }
@@ -146,17 +154,17 @@ pub fn get_gpg_keys() -> Result<Vec<(String, String)>> {
     let output = Command::new("gpg")
         .args(["--list-keys", "--with-colons"])
         .output()
-        .map_err(|e| Error::Cipher(format!("failed to execute gpg: {e}")))?;
+        .map_err(|e| gpg_error(format!("failed to execute gpg: {e}")))?;
 
     if !output.status.success() {
-        return Err(Error::Cipher(format!(
-            "gpg --list-keys failed (exit {})",
+        return Err(gpg_error(format!(
+            "--list-keys failed (exit {})",
             output.status
         )));
     }
 
     let stdout = String::from_utf8(output.stdout)
-        .map_err(|_| Error::Msg("failed to parse GPG output as UTF-8".to_string()))?;
+        .map_err(|_| gpg_error("failed to parse output as UTF-8".to_string()))?;
 
     let mut available_keys: Vec<(String, String)> = Vec::new();
     let mut current_fingerprint: Option<String> = None;
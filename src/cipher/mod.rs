@@ -15,7 +15,10 @@ use std::any::Any;
 use strum_macros::{AsRefStr, EnumIter, EnumString};
 use zeroize::Zeroizing;
 
-use crate::{env::EnvMap, error::Result};
+use crate::{
+    env::EnvMap,
+    error::{Error, Result},
+};
 
 #[derive(Copy, Clone, PartialEq, Serialize, Deserialize, EnumIter, EnumString, AsRefStr)]
 #[serde(rename_all = "lowercase")]
@@ -34,7 +37,7 @@ impl std::fmt::Display for CipherKind {
 }
 
 #[serde_as]
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum EncryptedContent {
     Bytes(#[serde_as(as = "Base64")] Vec<u8>),
@@ -68,6 +71,27 @@ pub trait Cipher: Any + Send + DynClone {
     fn as_any_mut(&mut self) -> &mut dyn Any;
 }
 
+/// parses cipher metadata tagged with a `version` field, failing with
+/// [`Error::UnsupportedVersion`] for versions not in `supported`
+pub(crate) fn import_versioned<T: serde::de::DeserializeOwned>(
+    data: serde_json::Value,
+    supported: &[&str],
+) -> Result<T> {
+    let version = data
+        .get("version")
+        .and_then(|version| version.as_str())
+        .ok_or_else(|| Error::Corrupted("cipher metadata has no version".to_string()))?;
+
+    if !supported.contains(&version) {
+        return Err(Error::UnsupportedVersion {
+            kind: "cipher metadata".to_string(),
+            version: version.to_string(),
+        });
+    }
+
+    serde_json::from_value(data).map_err(|e| Error::Corrupted(e.to_string()))
+}
+
 impl Clone for Box<dyn Cipher> {
     fn clone(&self) -> Box<dyn Cipher> {
         dyn_clone::clone_box(self.as_ref())
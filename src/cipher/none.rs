@@ -20,9 +20,11 @@ impl Cipher for NONE {
 
     fn decrypt(&self, encrypted_data: &EncryptedContent) -> Result<EnvMap> {
         match encrypted_data {
-            EncryptedContent::Json(value) => Ok(serde_json::from_value(value.clone())?),
-            _ => Err(Error::Cipher(
-                "Encrypted data is not a JSON object".to_string(),
+            EncryptedContent::Json(value) => {
+                serde_json::from_value(value.clone()).map_err(|e| Error::Corrupted(e.to_string()))
+            }
+            _ => Err(Error::Corrupted(
+                "expected the variables as JSON, found encrypted data".to_string(),
             )),
         }
     }
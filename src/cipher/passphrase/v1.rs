@@ -88,6 +88,15 @@ pub fn encrypt(key: &str, data: &[u8]) -> Result<(Vec<u8>, MetadataV1)> {
 }
 
 pub fn decrypt(key: &str, metadata: &MetadataV1, encrypted_data: &[u8]) -> Result<Vec<u8>> {
+    let nonce_bytes = STANDARD
+        .decode(&metadata.nonce)
+        .map_err(|e| Error::Corrupted(format!("invalid nonce: {e}")))?;
+
+    // the stream nonce is 19 bytes, converting a slice of any other length panics
+    if nonce_bytes.len() != 19 {
+        return Err(Error::Corrupted("invalid nonce length".to_string()));
+    }
+
     let mut output_key_material = [0u8; 32];
 
     Argon2::new(
@@ -108,10 +117,6 @@ pub fn decrypt(key: &str, metadata: &MetadataV1, encrypted_data: &[u8]) -> Resul
     )
     .map_err(|e| Error::Cipher(e.to_string()))?;
 
-    let nonce_bytes = STANDARD
-        .decode(&metadata.nonce)
-        .map_err(|e| Error::Cipher(e.to_string()))?;
-
     let cipher = XChaCha20Poly1305::new(Key::from_slice(&output_key_material));
     output_key_material.zeroize();
 
@@ -126,11 +131,7 @@ pub fn decrypt(key: &str, metadata: &MetadataV1, encrypted_data: &[u8]) -> Resul
         let end = usize::min(offset + BUFFER_LEN, encrypted_data.len());
         let chunk = &encrypted_data[offset..end];
 
-        decrypted_buffer.extend(
-            decryptor
-                .decrypt_next(chunk)
-                .map_err(|e| Error::Cipher(e.to_string()))?,
-        );
+        decrypted_buffer.extend(decryptor.decrypt_next(chunk).map_err(|_| Error::WrongKey)?);
 
         offset = end;
     }
@@ -139,7 +140,7 @@ pub fn decrypt(key: &str, metadata: &MetadataV1, encrypted_data: &[u8]) -> Resul
     decrypted_buffer.extend(
         decryptor
             .decrypt_last(last_chunk)
-            .map_err(|e| Error::Cipher(e.to_string()))?,
+            .map_err(|_| Error::WrongKey)?,
     );
 
     Ok(decrypted_buffer)
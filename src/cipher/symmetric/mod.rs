@@ -9,7 +9,7 @@ use zeroize::Zeroizing;
 
 use crate::{
     EnvMap,
-    cipher::{Cipher, CipherKind, EncryptedContent},
+    cipher::{Cipher, CipherKind, EncryptedContent, import_versioned},
     error::Result,
 };
 
@@ -57,7 +57,7 @@ impl Cipher for SYMMETRIC {
         let decrypted = match &self.metadata {
             VersionedMetadata::V1(metadata) => v1::decrypt(&self.key, metadata, &raw_data)?,
         };
-        Ok(decrypted.into())
+        EnvMap::try_from(decrypted)
     }
 
     fn export_metadata(&self) -> Option<serde_json::Value> {
@@ -65,7 +65,7 @@ impl Cipher for SYMMETRIC {
     }
 
     fn import_metadata(&mut self, data: serde_json::Value) -> Result<()> {
-        self.metadata = serde_json::from_value(data)?;
+        self.metadata = import_versioned(data, &["1"])?;
 
         Ok(())
     }
@@ -63,19 +63,21 @@ pub fn encrypt(key: &str, data: &[u8]) -> Result<(Vec<u8>, MetadataV1)> {
 }
 
 pub fn decrypt(key: &str, metadata: &MetadataV1, encrypted_data: &[u8]) -> Result<Vec<u8>> {
-    let key_bytes = STANDARD
-        .decode(key)
-        .map_err(|e| Error::Cipher(e.to_string()))?;
+    // a key that isn't even well formed can't be the right one
+    let key_bytes = STANDARD.decode(key).map_err(|_| Error::WrongKey)?;
 
     if key_bytes.len() != 32 {
-        return Err(Error::Cipher(
-            "Symmetric key must be exactly 32 bytes (base64 encoded)".to_string(),
-        ));
+        return Err(Error::WrongKey);
     }
 
     let nonce_bytes = STANDARD
         .decode(&metadata.nonce)
-        .map_err(|e| Error::Cipher(e.to_string()))?;
+        .map_err(|e| Error::Corrupted(format!("invalid nonce: {e}")))?;
+
+    // the stream nonce is 19 bytes, converting a slice of any other length panics
+    if nonce_bytes.len() != 19 {
+        return Err(Error::Corrupted("invalid nonce length".to_string()));
+    }
 
     let cipher = XChaCha20Poly1305::new(Key::from_slice(&key_bytes));
 
@@ -90,11 +92,7 @@ pub fn decrypt(key: &str, metadata: &MetadataV1, encrypted_data: &[u8]) -> Resul
         let end = usize::min(offset + BUFFER_LEN, encrypted_data.len());
         let chunk = &encrypted_data[offset..end];
 
-        decrypted_buffer.extend(
-            decryptor
-                .decrypt_next(chunk)
-                .map_err(|e| Error::Cipher(e.to_string()))?,
-        );
+        decrypted_buffer.extend(decryptor.decrypt_next(chunk).map_err(|_| Error::WrongKey)?);
 
         offset = end;
     }
@@ -103,7 +101,7 @@ pub fn decrypt(key: &str, metadata: &MetadataV1, encrypted_data: &[u8]) -> Resul
     decrypted_buffer.extend(
         decryptor
             .decrypt_last(last_chunk)
-            .map_err(|e| Error::Cipher(e.to_string()))?,
+            .map_err(|_| Error::WrongKey)?,
     );
 
     Ok(decrypted_buffer)
@@ -0,0 +1,43 @@
+//! Passes profile variables to a single child process instead of the
+//! environment of the current process.
+//!
+//! ```ignore
+//! use envio::command::CommandExt;
+//!
+//! let profile = envio::get_profile(path, key_provider)?;
+//! std::process::Command::new("npm")
+//!     .args(["run", "dev"])
+//!     .with_profile(&profile)
+//!     .status()?;
+//! ```
+
+use crate::{env::EnvMap, profile::Profile};
+
+pub trait CommandExt {
+    /// sets the non expired variables of `envs` on the child, later calls
+    /// override earlier ones
+    fn with_envs(&mut self, envs: &EnvMap) -> &mut Self;
+
+    fn with_profile(&mut self, profile: &Profile) -> &mut Self {
+        self.with_envs(&profile.envs)
+    }
+}
+
+impl CommandExt for std::process::Command {
+    fn with_envs(&mut self, envs: &EnvMap) -> &mut Self {
+        self.envs(active_vars(envs))
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl CommandExt for tokio::process::Command {
+    fn with_envs(&mut self, envs: &EnvMap) -> &mut Self {
+        self.envs(active_vars(envs))
+    }
+}
+
+fn active_vars(envs: &EnvMap) -> impl Iterator<Item = (&str, &str)> {
+    envs.iter()
+        .filter(|env| !env.is_expired())
+        .map(|env| (env.key.as_str(), env.value.as_str()))
+}
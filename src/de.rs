@@ -0,0 +1,779 @@
+//! Deserializes an [`EnvMap`] into a config struct, see [`EnvMap::deserialize`].
+//!
+//! Field names are matched to `SCREAMING_SNAKE_CASE` keys, `database_url` and
+//! `databaseUrl` both read `DATABASE_URL`. Values are parsed as
+//!
+//! - numbers and `char`s with [`str::parse`]
+//! - `bool`s from `true`/`false`, `1`/`0`, `yes`/`no` and `on`/`off`
+//! - sequences like `Vec<T>` from comma separated items
+//! - [`Duration`]s with [`parse_duration`]
+//! - unit enum variants by name, ignoring case
+//! - `Option<T>` as `None` when the variable is not set
+
+use std::{cell::RefCell, fmt::Display, time::Duration};
+
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+};
+
+use crate::{
+    env::EnvMap,
+    error::{Error, Result},
+};
+
+/// a variable that is missing or can't be parsed into its field
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldError {
+    pub key: String,
+    pub message: String,
+}
+
+impl Display for FieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.key.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "`{}` {}", self.key, self.message)
+        }
+    }
+}
+
+/// Deserializes `envs` into `T`, matching the fields of `T` to `PREFIX` +
+/// `SCREAMING_SNAKE_CASE` keys.
+///
+/// Instead of stopping at the first problem every missing or invalid field is
+/// collected into one [`Error::Deserialize`].
+pub fn from_env_map<T: DeserializeOwned>(envs: &EnvMap, prefix: Option<&str>) -> Result<T> {
+    let state = State {
+        prefix: prefix.unwrap_or_default().to_ascii_uppercase(),
+        secrets: envs
+            .iter()
+            .filter(|env| env.is_secret())
+            .map(|env| env.key.clone())
+            .collect(),
+        errors: RefCell::new(Vec::new()),
+        missing: RefCell::new(Vec::new()),
+    };
+
+    // serde reports one missing field at a time, so every missing field found
+    // gets a placeholder and the next attempt finds the one after it
+    loop {
+        state.errors.borrow_mut().clear();
+
+        let result = T::deserialize(EnvMapDeserializer {
+            envs,
+            state: &state,
+        });
+
+        let mut errors = state.errors.take();
+
+        match result {
+            // placeholders make the missing fields deserialize, they are still errors
+            Ok(value) if errors.is_empty() && state.missing.borrow().is_empty() => {
+                return Ok(value);
+            }
+            Ok(_) => {}
+            Err(DeError::Missing(field)) if !state.missing.borrow().contains(&field) => {
+                state.missing.borrow_mut().push(field);
+                continue;
+            }
+            // already reported, the placeholder didn't satisfy it
+            Err(DeError::Missing(_)) => {}
+            Err(DeError::Custom(message)) => errors.push(FieldError {
+                key: String::new(),
+                message,
+            }),
+        }
+
+        let mut all: Vec<FieldError> = state
+            .missing
+            .take()
+            .into_iter()
+            .map(|field| FieldError {
+                key: state.key_for(field),
+                message: "is missing".to_string(),
+            })
+            .collect();
+        all.append(&mut errors);
+
+        return Err(Error::Deserialize(all));
+    }
+}
+
+/// `30s`, `5m`, `1h30m`, `250ms` or a bare number of seconds
+pub fn parse_duration(value: &str) -> std::result::Result<Duration, String> {
+    let value = value.trim();
+
+    if let Ok(secs) = value.parse::<u64>() {
+        return Ok(Duration::from_secs(secs));
+    }
+
+    let mut total = Duration::ZERO;
+    let mut rest = value;
+
+    if rest.is_empty() {
+        return Err("expected a duration like `30s` or `5m`".to_string());
+    }
+
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let unit_len = rest[digits..]
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(rest.len() - digits);
+
+        let amount: u64 = rest[..digits]
+            .parse()
+            .map_err(|_| format!("invalid duration `{}`", value))?;
+        let part = match &rest[digits..digits + unit_len] {
+            "ns" => Some(Duration::from_nanos(amount)),
+            "us" => Some(Duration::from_micros(amount)),
+            "ms" => Some(Duration::from_millis(amount)),
+            "s" => Some(Duration::from_secs(amount)),
+            "m" => amount.checked_mul(60).map(Duration::from_secs),
+            "h" => amount.checked_mul(60 * 60).map(Duration::from_secs),
+            "d" => amount.checked_mul(24 * 60 * 60).map(Duration::from_secs),
+            unit => return Err(format!("unknown duration unit `{}`", unit)),
+        };
+
+        total = part
+            .and_then(|part| total.checked_add(part))
+            .ok_or_else(|| format!("duration `{}` is too large", value))?;
+        rest = &rest[digits + unit_len..];
+    }
+
+    Ok(total)
+}
+
+/// `database_url` and `databaseUrl` -> `DATABASE_URL`
+fn screaming_snake_case(field: &str) -> String {
+    let mut key = String::with_capacity(field.len() + 4);
+
+    for (i, c) in field.chars().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 && !key.ends_with('_') {
+            key.push('_');
+        }
+        key.push(if c == '-' {
+            '_'
+        } else {
+            c.to_ascii_uppercase()
+        });
+    }
+
+    key
+}
+
+struct State {
+    prefix: String,
+    /// keys of the secret variables, their values are left out of errors
+    secrets: Vec<String>,
+    errors: RefCell<Vec<FieldError>>,
+    /// fields found missing by earlier attempts, they get placeholder values
+    missing: RefCell<Vec<&'static str>>,
+}
+
+impl State {
+    fn key_for(&self, field: &str) -> String {
+        format!("{}{}", self.prefix, screaming_snake_case(field))
+    }
+
+    fn record(&self, key: &str, message: impl Display) {
+        self.errors.borrow_mut().push(FieldError {
+            key: key.to_string(),
+            message: message.to_string(),
+        });
+    }
+}
+
+#[derive(Debug)]
+enum DeError {
+    Missing(&'static str),
+    Custom(String),
+}
+
+impl Display for DeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeError::Missing(field) => write!(f, "missing field `{}`", field),
+            DeError::Custom(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for DeError {}
+
+impl de::Error for DeError {
+    fn custom<T: Display>(msg: T) -> Self {
+        DeError::Custom(msg.to_string())
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        DeError::Missing(field)
+    }
+}
+
+struct EnvMapDeserializer<'a> {
+    envs: &'a EnvMap,
+    state: &'a State,
+}
+
+impl<'de> de::Deserializer<'de> for EnvMapDeserializer<'_> {
+    type Error = DeError;
+
+    fn deserialize_any<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        self.deserialize_map(visitor)
+    }
+
+    /// every prefixed variable, with the prefix removed
+    fn deserialize_map<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        let entries = self
+            .envs
+            .iter()
+            .filter_map(|env| {
+                let key = env.key.to_ascii_uppercase();
+                let name = key.strip_prefix(&self.state.prefix)?.to_string();
+                Some((name, Some(env.key.clone()), Some(env.value.clone())))
+            })
+            .collect();
+
+        visitor.visit_map(FieldsAccess {
+            entries,
+            index: 0,
+            state: self.state,
+        })
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        let missing = self.state.missing.borrow().clone();
+
+        let entries = fields
+            .iter()
+            .filter_map(|field| {
+                let key = self.state.key_for(field);
+                let env = self
+                    .envs
+                    .iter()
+                    .find(|env| env.key.eq_ignore_ascii_case(&key));
+
+                match env {
+                    Some(env) => Some((
+                        field.to_string(),
+                        Some(env.key.clone()),
+                        Some(env.value.clone()),
+                    )),
+                    None if missing.contains(field) => Some((field.to_string(), Some(key), None)),
+                    None => None,
+                }
+            })
+            .collect();
+
+        visitor.visit_map(FieldsAccess {
+            entries,
+            index: 0,
+            state: self.state,
+        })
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct enum identifier ignored_any
+    }
+}
+
+/// `(field, key, value)`, a `None` value stands in for a missing field
+type Entry = (String, Option<String>, Option<String>);
+
+struct FieldsAccess<'a> {
+    entries: Vec<Entry>,
+    index: usize,
+    state: &'a State,
+}
+
+impl<'de> MapAccess<'de> for FieldsAccess<'_> {
+    type Error = DeError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> std::result::Result<Option<K::Value>, DeError> {
+        let Some((field, _, _)) = self.entries.get(self.index) else {
+            return Ok(None);
+        };
+
+        seed.deserialize(field.clone().into_deserializer())
+            .map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        let (_, key, value) = self.entries[self.index].clone();
+        self.index += 1;
+
+        seed.deserialize(ValueDeserializer {
+            key: key.unwrap_or_default(),
+            value,
+            state: self.state,
+        })
+    }
+}
+
+/// One variable. Invalid values are recorded in [`State::errors`] and replaced
+/// by a placeholder so the remaining fields still get checked.
+struct ValueDeserializer<'a> {
+    key: String,
+    /// `None` for a placeholder
+    value: Option<String>,
+    state: &'a State,
+}
+
+impl ValueDeserializer<'_> {
+    fn is_secret(&self) -> bool {
+        self.state.secrets.contains(&self.key)
+    }
+
+    /// `value` as quoted in errors, secret values are not shown
+    fn shown(&self, value: &str) -> String {
+        if self.is_secret() {
+            "a secret value".to_string()
+        } else {
+            format!("`{}`", value)
+        }
+    }
+
+    fn parse<T: std::str::FromStr>(&self, expected: &str) -> Option<T> {
+        let value = self.value.as_deref()?;
+
+        match value.trim().parse() {
+            Ok(parsed) => Some(parsed),
+            Err(_) => {
+                self.state.record(
+                    &self.key,
+                    format!("expected {}, found {}", expected, self.shown(value)),
+                );
+                None
+            }
+        }
+    }
+}
+
+macro_rules! deserialize_number {
+    ($($method:ident => $visit:ident, $expected:expr;)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> std::result::Result<V::Value, DeError> {
+                visitor.$visit(self.parse($expected).unwrap_or_default())
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for ValueDeserializer<'_> {
+    type Error = DeError;
+
+    fn deserialize_any<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        visitor.visit_string(self.value.unwrap_or_default())
+    }
+
+    deserialize_number! {
+        deserialize_i8 => visit_i8, "a `i8`";
+        deserialize_i16 => visit_i16, "a `i16`";
+        deserialize_i32 => visit_i32, "a `i32`";
+        deserialize_i64 => visit_i64, "a `i64`";
+        deserialize_i128 => visit_i128, "a `i128`";
+        deserialize_u8 => visit_u8, "a `u8`";
+        deserialize_u16 => visit_u16, "a `u16`";
+        deserialize_u32 => visit_u32, "a `u32`";
+        deserialize_u64 => visit_u64, "a `u64`";
+        deserialize_u128 => visit_u128, "a `u128`";
+        deserialize_f32 => visit_f32, "a `f32`";
+        deserialize_f64 => visit_f64, "a `f64`";
+        deserialize_char => visit_char, "a single character";
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        let value = match self.value.as_deref().map(|v| v.trim().to_ascii_lowercase()) {
+            None => false,
+            Some(v) if matches!(v.as_str(), "true" | "1" | "yes" | "on") => true,
+            Some(v) if matches!(v.as_str(), "false" | "0" | "no" | "off" | "") => false,
+            Some(v) => {
+                let message = format!("expected true or false, found {}", self.shown(&v));
+                self.state.record(&self.key, message);
+                false
+            }
+        };
+
+        visitor.visit_bool(value)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        match self.value {
+            Some(_) => visitor.visit_some(self),
+            None => visitor.visit_none(),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    /// comma separated, `a, b,c` -> `["a", "b", "c"]`
+    fn deserialize_seq<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        let items = self
+            .value
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+            .into_iter();
+
+        visitor.visit_seq(ItemsAccess {
+            key: self.key,
+            items,
+            state: self.state,
+        })
+    }
+
+    /// `std::time::Duration` is the only struct a single value can hold
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        if name != "Duration" || fields != ["secs", "nanos"] {
+            return Err(de::Error::custom(format!(
+                "`{}` can't be read from a single variable",
+                name
+            )));
+        }
+
+        let duration = match self.value.as_deref().map(parse_duration) {
+            Some(Ok(duration)) => duration,
+            Some(Err(_)) if self.is_secret() => {
+                self.state
+                    .record(&self.key, "expected a duration like `30s` or `5m`");
+                Duration::ZERO
+            }
+            Some(Err(e)) => {
+                self.state.record(&self.key, e);
+                Duration::ZERO
+            }
+            None => Duration::ZERO,
+        };
+
+        visitor.visit_map(de::value::MapDeserializer::new(
+            [
+                ("secs", duration.as_secs()),
+                ("nanos", duration.subsec_nanos() as u64),
+            ]
+            .into_iter(),
+        ))
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        let value = self
+            .value
+            .clone()
+            .unwrap_or_else(|| variants[0].to_string());
+
+        match variants
+            .iter()
+            .find(|variant| variant.eq_ignore_ascii_case(value.trim()))
+        {
+            Some(variant) => visitor.visit_enum((*variant).into_deserializer()),
+            None => {
+                self.state.record(
+                    &self.key,
+                    format!(
+                        "expected one of {}, found {}",
+                        variants.join(", "),
+                        self.shown(&value)
+                    ),
+                );
+                visitor.visit_enum(variants[0].into_deserializer())
+            }
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        str string bytes byte_buf unit unit_struct tuple tuple_struct map
+        identifier ignored_any
+    }
+}
+
+struct ItemsAccess<'a> {
+    key: String,
+    items: std::vec::IntoIter<String>,
+    state: &'a State,
+}
+
+impl<'de> SeqAccess<'de> for ItemsAccess<'_> {
+    type Error = DeError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> std::result::Result<Option<T::Value>, DeError> {
+        let Some(item) = self.items.next() else {
+            return Ok(None);
+        };
+
+        seed.deserialize(ValueDeserializer {
+            key: self.key.clone(),
+            value: Some(item),
+            state: self.state,
+        })
+        .map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, time::Duration};
+
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::env::{Env, Sensitivity};
+
+    fn envs(pairs: &[(&str, &str)]) -> EnvMap {
+        let mut envs = EnvMap::default();
+        for (key, value) in pairs {
+            envs.insert_from_key_value(*key, *value);
+        }
+        envs
+    }
+
+    fn field_errors(error: Error) -> Vec<FieldError> {
+        match error {
+            Error::Deserialize(errors) => errors,
+            other => panic!("expected a deserialize error, got {}", other),
+        }
+    }
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    #[serde(rename_all = "lowercase")]
+    enum Mode {
+        Development,
+        Production,
+    }
+
+    #[derive(Deserialize, Debug)]
+    struct Config {
+        database_url: String,
+        port: u16,
+        debug: bool,
+        mode: Mode,
+        timeout: Duration,
+        hosts: Vec<String>,
+        #[serde(default)]
+        workers: Option<u8>,
+    }
+
+    fn valid_envs() -> EnvMap {
+        envs(&[
+            ("DATABASE_URL", "postgres://localhost/app"),
+            ("PORT", " 8080 "),
+            ("DEBUG", "yes"),
+            ("MODE", "Production"),
+            ("TIMEOUT", "1h30m"),
+            ("HOSTS", "a.example, b.example,,"),
+        ])
+    }
+
+    #[test]
+    fn reads_every_supported_type() {
+        let config: Config = from_env_map(&valid_envs(), None).unwrap();
+
+        assert_eq!(config.database_url, "postgres://localhost/app");
+        assert_eq!(config.port, 8080);
+        assert!(config.debug);
+        assert_eq!(config.mode, Mode::Production);
+        assert_eq!(config.timeout, Duration::from_secs(90 * 60));
+        assert_eq!(config.hosts, ["a.example", "b.example"]);
+        assert_eq!(config.workers, None);
+    }
+
+    #[test]
+    fn matches_camel_case_fields() {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Camel {
+            database_url: String,
+        }
+
+        let camel: Camel = from_env_map(&envs(&[("DATABASE_URL", "x")]), None).unwrap();
+        assert_eq!(camel.database_url, "x");
+    }
+
+    #[test]
+    fn reads_prefixed_keys() {
+        #[derive(Deserialize)]
+        struct Prefixed {
+            port: u16,
+        }
+
+        let envs = envs(&[("PORT", "1"), ("APP_PORT", "2")]);
+
+        let prefixed: Prefixed = from_env_map(&envs, Some("app_")).unwrap();
+        assert_eq!(prefixed.port, 2);
+
+        let errors = field_errors(
+            from_env_map::<Prefixed>(&envs, Some("OTHER_"))
+                .err()
+                .unwrap(),
+        );
+        assert_eq!(errors[0].key, "OTHER_PORT");
+    }
+
+    #[test]
+    fn maps_strip_the_prefix() {
+        let envs = envs(&[("APP_A", "1"), ("APP_B", "2"), ("OTHER", "3")]);
+
+        let map: HashMap<String, String> = from_env_map(&envs, Some("APP_")).unwrap();
+        assert_eq!(map.len(), 2);
+        assert_eq!(map["A"], "1");
+        assert_eq!(map["B"], "2");
+    }
+
+    #[test]
+    fn reports_every_missing_field() {
+        let errors = field_errors(from_env_map::<Config>(&envs(&[]), None).err().unwrap());
+
+        let keys: Vec<&str> = errors.iter().map(|e| e.key.as_str()).collect();
+        assert_eq!(
+            keys,
+            ["DATABASE_URL", "PORT", "DEBUG", "MODE", "TIMEOUT", "HOSTS"]
+        );
+        assert!(errors.iter().all(|e| e.message == "is missing"));
+    }
+
+    #[test]
+    fn collects_missing_and_invalid_fields_together() {
+        let mut envs = valid_envs();
+        envs.remove("DATABASE_URL").unwrap();
+        envs.insert_from_key_value("PORT", "eighty");
+        envs.insert_from_key_value("DEBUG", "maybe");
+        envs.insert_from_key_value("MODE", "staging");
+        envs.insert_from_key_value("TIMEOUT", "5 parsecs");
+
+        let errors = field_errors(from_env_map::<Config>(&envs, None).err().unwrap());
+        let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+
+        assert_eq!(
+            messages,
+            [
+                "`DATABASE_URL` is missing",
+                "`PORT` expected a `u16`, found `eighty`",
+                "`DEBUG` expected true or false, found `maybe`",
+                "`MODE` expected one of development, production, found `staging`",
+                "`TIMEOUT` unknown duration unit ` parsecs`",
+            ]
+        );
+    }
+
+    #[test]
+    fn bool_spellings() {
+        #[derive(Deserialize)]
+        struct Flag {
+            flag: bool,
+        }
+
+        for (value, expected) in [
+            ("true", true),
+            ("ON", true),
+            ("1", true),
+            ("no", false),
+            ("off", false),
+            ("", false),
+        ] {
+            let flag: Flag = from_env_map(&envs(&[("FLAG", value)]), None).unwrap();
+            assert_eq!(flag.flag, expected, "{:?}", value);
+        }
+    }
+
+    #[test]
+    fn secret_values_stay_out_of_errors() {
+        #[derive(Deserialize, Debug)]
+        #[allow(dead_code)]
+        struct Secrets {
+            port: u16,
+            debug: bool,
+            mode: Mode,
+            timeout: Duration,
+        }
+
+        let mut envs = EnvMap::default();
+        for key in ["PORT", "DEBUG", "MODE", "TIMEOUT"] {
+            let mut env = Env::from_key_value(key, "hunter2");
+            env.sensitivity = Sensitivity::Secret;
+            envs.insert(env);
+        }
+
+        let errors = field_errors(from_env_map::<Secrets>(&envs, None).err().unwrap());
+
+        assert_eq!(errors.len(), 4);
+        for error in errors {
+            assert!(!error.message.contains("hunter2"), "{}", error);
+        }
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(parse_duration("30"), Ok(Duration::from_secs(30)));
+        assert_eq!(parse_duration("250ms"), Ok(Duration::from_millis(250)));
+        assert_eq!(
+            parse_duration("1d2h"),
+            Ok(Duration::from_secs(26 * 60 * 60))
+        );
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("5y").is_err());
+        assert!(parse_duration("m").is_err());
+        assert!(parse_duration(&format!("{}h", u64::MAX)).is_err());
+    }
+
+    #[test]
+    fn field_names_to_keys() {
+        assert_eq!(screaming_snake_case("database_url"), "DATABASE_URL");
+        assert_eq!(screaming_snake_case("databaseUrl"), "DATABASE_URL");
+        assert_eq!(screaming_snake_case("api-key"), "API_KEY");
+    }
+}
@@ -1,15 +1,88 @@
-use chrono::NaiveDate;
+use chrono::{DateTime, Local, NaiveDate};
 use indexmap::IndexMap;
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::DeserializeOwned};
 
 use crate::error::{Error, Result};
 
+/// starts [`EnvMap::as_bytes`], the variables follow as JSON so fields added to
+/// [`Env`] can fall back to defaults. Bytes without it are the postcard encoding
+/// of [`LegacyEnv`]s written by older versions. Ten bytes with the continuation
+/// bit set are never a valid postcard length, so older versions reject the new
+/// encoding instead of reading an empty profile
+const JSON_MARKER: &[u8] = b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\xffjson";
+
+/// how many replaced values [`Env::rotate`] keeps
+pub const HISTORY_LIMIT: usize = 5;
+
+/// whether envio masks a value when displaying it, secret or not every value
+/// is encrypted the same way
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Sensitivity {
+    #[default]
+    Plain,
+    Secret,
+}
+
+/// how often a variable should get a new value and how to get it
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct Rotation {
+    pub interval_days: u32,
+    /// shell command printing the new value, it gets the old value on stdin
+    #[serde(default)]
+    pub command: Option<String>,
+}
+
+/// a value replaced by [`Env::rotate`]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PreviousValue {
+    pub value: String,
+    /// the expiration date the value had, restored by [`Env::rollback`]
+    #[serde(default)]
+    pub expiration_date: Option<NaiveDate>,
+    pub replaced_at: DateTime<Local>,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct Env {
     pub key: String,
     pub value: String,
     pub comment: Option<String>,
     pub expiration_date: Option<NaiveDate>,
+    #[serde(default)]
+    pub sensitivity: Sensitivity,
+    /// set by [`EnvMap::insert`], `None` for variables saved before envio
+    /// recorded it
+    #[serde(default)]
+    pub created_at: Option<DateTime<Local>>,
+    /// set by [`EnvMap::insert`] when the value changes
+    #[serde(default)]
+    pub updated_at: Option<DateTime<Local>>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// free-form metadata such as `owner=payments` or `rotate=https://...`
+    #[serde(default)]
+    pub labels: IndexMap<String, String>,
+    #[serde(default)]
+    pub rotation: Option<Rotation>,
+    /// values replaced by [`Env::rotate`], the most recent first
+    #[serde(default)]
+    pub history: Vec<PreviousValue>,
+}
+
+/// [`Env`] as encoded by versions before [`JSON_MARKER`]
+#[derive(Deserialize)]
+struct LegacyEnv {
+    key: String,
+    value: String,
+    comment: Option<String>,
+    expiration_date: Option<NaiveDate>,
+}
+
+impl From<LegacyEnv> for Env {
+    fn from(env: LegacyEnv) -> Self {
+        Env::new(env.key, env.value, env.comment, env.expiration_date)
+    }
 }
 
 impl Env {
@@ -24,6 +97,13 @@ impl Env {
             value: value.into(),
             comment,
             expiration_date,
+            sensitivity: Sensitivity::Plain,
+            created_at: None,
+            updated_at: None,
+            tags: Vec::new(),
+            labels: IndexMap::new(),
+            rotation: None,
+            history: Vec::new(),
         }
     }
 
@@ -31,6 +111,87 @@ impl Env {
         Self::new(key, value, None, None)
     }
 
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+
+    /// takes the timestamps over from `previous`, the variable this one replaces,
+    /// `updated_at` is set to now when the value differs. Without `previous` the
+    /// timestamps not set yet are set to now.
+    pub fn touch(&mut self, previous: Option<&Env>) {
+        let now = Local::now();
+
+        match previous {
+            Some(previous) => {
+                self.created_at = previous.created_at.or(self.created_at);
+                self.updated_at = if previous.value != self.value {
+                    Some(now)
+                } else {
+                    previous.updated_at.or(self.updated_at)
+                };
+            }
+            None => {
+                self.created_at.get_or_insert(now);
+                self.updated_at.get_or_insert(now);
+            }
+        }
+    }
+
+    pub fn is_secret(&self) -> bool {
+        self.sensitivity == Sensitivity::Secret
+    }
+
+    /// replaces the value with `value`, keeping the old one and its expiration
+    /// date in [`Env::history`]
+    pub fn rotate(&mut self, value: impl Into<String>) {
+        let previous = std::mem::replace(&mut self.value, value.into());
+        let now = Local::now();
+
+        self.history.insert(
+            0,
+            PreviousValue {
+                value: previous,
+                expiration_date: self.expiration_date,
+                replaced_at: now,
+            },
+        );
+        self.history.truncate(HISTORY_LIMIT);
+        self.updated_at = Some(now);
+    }
+
+    /// restores the most recently replaced value with its expiration date,
+    /// dropping the current one. Returns `false` when there is nothing to restore
+    pub fn rollback(&mut self) -> bool {
+        if self.history.is_empty() {
+            return false;
+        }
+
+        let previous = self.history.remove(0);
+        self.value = previous.value;
+        self.expiration_date = previous.expiration_date;
+        self.updated_at = Some(Local::now());
+        true
+    }
+
+    /// the day the value is due for rotation, `None` without a rotation interval
+    /// or when envio doesn't know when the value last changed
+    pub fn rotation_due_date(&self) -> Option<NaiveDate> {
+        let rotation = self.rotation.as_ref()?;
+        let changed = self.updated_at.or(self.created_at)?;
+
+        changed
+            .date_naive()
+            .checked_add_days(chrono::Days::new(rotation.interval_days.into()))
+    }
+
+    /// a variable with a rotation interval that never recorded a change is due
+    pub fn is_rotation_due(&self) -> bool {
+        self.rotation.is_some()
+            && self
+                .rotation_due_date()
+                .is_none_or(|date| date <= Local::now().date_naive())
+    }
+
     pub fn is_expired(&self) -> bool {
         if let Some(date) = self.expiration_date {
             date <= chrono::Local::now().date_naive()
@@ -67,10 +228,15 @@ impl<'de> Deserialize<'de> for EnvMap {
 
 impl EnvMap {
     pub fn as_bytes(&self) -> Result<Vec<u8>> {
-        Ok(postcard::to_allocvec(self)?)
+        let mut bytes = JSON_MARKER.to_vec();
+        serde_json::to_writer(&mut bytes, self)?;
+        Ok(bytes)
     }
 
-    pub fn insert(&mut self, env: Env) {
+    /// adds `env` or replaces the variable with the same key, keeping its
+    /// position, see [`Env::touch`] for the timestamps
+    pub fn insert(&mut self, mut env: Env) {
+        env.touch(self.envs.get(&env.key));
         self.envs.insert(env.key.clone(), env);
     }
 
@@ -85,10 +251,36 @@ impl EnvMap {
         Ok(())
     }
 
+    /// renames `key` in place, keeping its position and everything but the key
+    pub fn rename(&mut self, key: &str, new_key: impl Into<String>) -> Result<()> {
+        let new_key = new_key.into();
+
+        if new_key != key && self.envs.contains_key(&new_key) {
+            return Err(Error::Msg(format!(
+                "environment variable `{}` already exists",
+                new_key
+            )));
+        }
+
+        let Some((index, _, mut env)) = self.envs.shift_remove_full(key) else {
+            return Err(Error::EnvDoesNotExist(key.to_string()));
+        };
+
+        env.key = new_key.clone();
+        self.envs.shift_insert(index, new_key, env);
+        Ok(())
+    }
+
     pub fn get(&self, key: &str) -> Option<&Env> {
         self.envs.get(key)
     }
 
+    /// the variable for `key`, timestamps are not updated for changes made
+    /// through it
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut Env> {
+        self.envs.get_mut(key)
+    }
+
     pub fn contains_key(&self, key: &str) -> bool {
         self.envs.contains_key(key)
     }
@@ -119,6 +311,18 @@ impl EnvMap {
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Env> {
         self.envs.values_mut()
     }
+
+    /// Reads the variables into a config struct, `database_url` is read from
+    /// `DATABASE_URL`. See [`crate::de`] for the supported field types.
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T> {
+        crate::de::from_env_map(self, None)
+    }
+
+    /// like [`EnvMap::deserialize`] but `database_url` is read from
+    /// `{prefix}DATABASE_URL`
+    pub fn deserialize_prefixed<T: DeserializeOwned>(&self, prefix: &str) -> Result<T> {
+        crate::de::from_env_map(self, Some(prefix))
+    }
 }
 
 impl From<IndexMap<String, String>> for EnvMap {
@@ -137,25 +341,36 @@ impl From<EnvMap> for IndexMap<String, String> {
     }
 }
 
+/// keeps the variables as they are, unlike [`EnvMap::insert`] it doesn't touch
+/// their timestamps
 impl From<Vec<Env>> for EnvMap {
     fn from(envs: Vec<Env>) -> Self {
-        let mut env_map = Self::default();
-        for env in envs {
-            env_map.insert(env);
+        Self {
+            envs: envs.into_iter().map(|env| (env.key.clone(), env)).collect(),
         }
-        env_map
     }
 }
 
-impl From<&[u8]> for EnvMap {
-    fn from(bytes: &[u8]) -> Self {
-        postcard::from_bytes(bytes).expect("Failed to deserialize bytes to EnvMap")
+/// decodes the output of [`EnvMap::as_bytes`], failing with [`Error::Corrupted`]
+impl TryFrom<&[u8]> for EnvMap {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        if let Some(json) = bytes.strip_prefix(JSON_MARKER) {
+            return serde_json::from_slice(json).map_err(|e| Error::Corrupted(e.to_string()));
+        }
+
+        let envs: Vec<LegacyEnv> =
+            postcard::from_bytes(bytes).map_err(|e| Error::Corrupted(e.to_string()))?;
+        Ok(envs.into_iter().map(Env::from).collect::<Vec<_>>().into())
     }
 }
 
-impl From<Vec<u8>> for EnvMap {
-    fn from(bytes: Vec<u8>) -> Self {
-        EnvMap::from(bytes.as_slice())
+impl TryFrom<Vec<u8>> for EnvMap {
+    type Error = Error;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self> {
+        EnvMap::try_from(bytes.as_slice())
     }
 }
 
@@ -0,0 +1,147 @@
+use indexmap::IndexMap;
+
+use crate::env::{Env, EnvMap};
+
+/// variables carried over from the parent environment when [`EnvironmentOptions::clean`] is set
+#[cfg(target_family = "unix")]
+pub const DEFAULT_CLEAN_ALLOWLIST: &[&str] = &[
+    "PATH", "HOME", "TERM", "USER", "LOGNAME", "SHELL", "LANG", "TMPDIR", "TZ",
+];
+
+/// variables carried over from the parent environment when [`EnvironmentOptions::clean`] is set
+#[cfg(target_family = "windows")]
+pub const DEFAULT_CLEAN_ALLOWLIST: &[&str] = &[
+    "PATH",
+    "PATHEXT",
+    "SYSTEMROOT",
+    "COMSPEC",
+    "TEMP",
+    "TMP",
+    "USERPROFILE",
+    "HOMEDRIVE",
+    "HOMEPATH",
+];
+
+#[derive(Clone, Default)]
+pub struct EnvironmentOptions {
+    /// start from an empty environment instead of inheriting the parent one
+    pub clean: bool,
+    /// extra parent variables to keep in addition to [`DEFAULT_CLEAN_ALLOWLIST`]
+    pub keep: Vec<String>,
+    /// only add profile variables whose key matches one of these patterns
+    pub only: Vec<String>,
+    /// skip profile variables whose key matches one of these patterns
+    pub except: Vec<String>,
+    /// only add profile variables with one of these tags
+    pub tags: Vec<String>,
+    /// prepended to every profile key
+    pub prefix: Option<String>,
+    /// removed from the start of profile keys that have it
+    pub strip_prefix: Option<String>,
+}
+
+impl EnvironmentOptions {
+    fn is_kept(&self, key: &str) -> bool {
+        DEFAULT_CLEAN_ALLOWLIST
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(key))
+            || self
+                .keep
+                .iter()
+                .any(|pattern| matches_pattern(pattern, key))
+    }
+
+    fn is_selected(&self, env: &Env) -> bool {
+        let key = env.key.as_str();
+
+        if !self.only.is_empty() && !self.only.iter().any(|p| matches_pattern(p, key)) {
+            return false;
+        }
+
+        if !self.tags.is_empty() && !self.tags.iter().any(|tag| env.has_tag(tag)) {
+            return false;
+        }
+
+        !self.except.iter().any(|p| matches_pattern(p, key))
+    }
+
+    fn rewrite_key(&self, key: &str) -> String {
+        let key = match &self.strip_prefix {
+            Some(strip) => key.strip_prefix(strip.as_str()).unwrap_or(key),
+            None => key,
+        };
+
+        match &self.prefix {
+            Some(prefix) => format!("{prefix}{key}"),
+            None => key.to_string(),
+        }
+    }
+}
+
+/// Computes the environment a child process should see without touching the
+/// environment of the current process.
+///
+/// `parent` is the environment to start from (usually [`process_vars`]), and
+/// `layers` are applied in order so later profiles override earlier ones.
+/// Expired variables are skipped.
+pub fn build_environment<'a, P, L>(
+    parent: P,
+    layers: L,
+    options: &EnvironmentOptions,
+) -> IndexMap<String, String>
+where
+    P: IntoIterator<Item = (String, String)>,
+    L: IntoIterator<Item = &'a EnvMap>,
+{
+    let mut environment: IndexMap<String, String> = parent
+        .into_iter()
+        .filter(|(key, _)| !options.clean || options.is_kept(key))
+        .collect();
+
+    for envs in layers {
+        for env in envs {
+            if env.is_expired() || !options.is_selected(env) {
+                continue;
+            }
+
+            let key = options.rewrite_key(&env.key);
+            if key.is_empty() {
+                continue;
+            }
+
+            environment.insert(key, env.value.clone());
+        }
+    }
+
+    environment
+}
+
+/// environment of the current process, skipping variables that are not valid unicode
+pub fn process_vars() -> impl Iterator<Item = (String, String)> {
+    std::env::vars_os()
+        .filter_map(|(key, value)| Some((key.into_string().ok()?, value.into_string().ok()?)))
+}
+
+/// matches `key` against a pattern where `*` stands for any run of characters
+pub fn matches_pattern(pattern: &str, key: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+
+    let Some(mut rest) = key.strip_prefix(first) else {
+        return false;
+    };
+
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        return rest.is_empty();
+    };
+
+    for part in middle {
+        match rest.find(part) {
+            Some(idx) => rest = &rest[idx + part.len()..],
+            None => return false,
+        }
+    }
+
+    rest.len() >= last.len() && rest.ends_with(last)
+}
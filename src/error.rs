@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::de::FieldError;
+
 #[derive(Error, Debug)]
 #[non_exhaustive]
 pub enum Error {
@@ -12,12 +14,47 @@ pub enum Error {
     #[error(transparent)]
     Json(#[from] serde_json::Error),
 
+    #[error("profile `{0}` does not exist")]
+    ProfileDoesNotExist(String),
+
+    #[error("profile `{0}` already exists")]
+    ProfileExists(String),
+
+    /// a [`crate::store::ProfileStore`] failed for a reason other than I/O
+    #[error("profile store: {0}")]
+    Store(String),
+
     #[error("environment variable `{0}` does not exist")]
     EnvDoesNotExist(String),
 
     #[error("{0}")]
     Cipher(String),
 
+    /// the key doesn't decrypt the profile, the AEAD ciphers can't tell this
+    /// apart from a modified ciphertext
+    #[error("wrong key, the profile could not be decrypted")]
+    WrongKey,
+
+    /// the profile file or its decrypted content can't be parsed
+    #[error("profile is corrupted: {0}")]
+    Corrupted(String),
+
+    /// the profile was written in a format this version of envio doesn't know
+    #[error("unsupported {kind} version `{version}`, try updating envio")]
+    UnsupportedVersion { kind: String, version: String },
+
+    /// an external program like gpg is missing or failed
+    #[error("{tool}: {message}")]
+    ExternalTool { tool: String, message: String },
+
+    /// the key provider passed to [`crate::get_profile`] returned an error
+    #[error("{0}")]
+    KeyProviderFailed(Box<dyn std::error::Error + Send + Sync>),
+
+    /// every missing or invalid field found by [`crate::EnvMap::deserialize`]
+    #[error("{}", format_field_errors(.0))]
+    Deserialize(Vec<FieldError>),
+
     #[error(transparent)]
     Utf8Error(#[from] std::str::Utf8Error),
 
@@ -26,3 +63,8 @@ pub enum Error {
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
+
+fn format_field_errors(errors: &[FieldError]) -> String {
+    let errors: Vec<String> = errors.iter().map(ToString::to_string).collect();
+    format!("invalid configuration: {}", errors.join(", "))
+}
@@ -1,28 +1,75 @@
+pub mod backup;
 pub mod cipher;
+pub mod command;
+pub mod de;
 pub mod env;
+pub mod environment;
 pub mod error;
+pub mod lint;
 pub mod profile;
+pub mod secrets;
+pub mod store;
 pub mod utils;
 
-use std::path::Path;
+use indexmap::IndexMap;
+use std::{path::Path, sync::Arc};
 use zeroize::Zeroizing;
 
-pub use env::{Env, EnvMap};
+pub use env::{Env, EnvMap, PreviousValue, Rotation, Sensitivity};
 pub use profile::{Profile, ProfileMetadata};
 
 use crate::{
     cipher::{CipherKind, PASSPHRASE, SYMMETRIC},
     error::{Error, Result},
+    profile::{PROFILE_VERSION, SerializedProfile},
+    store::{ProfileStore, SingleFileStore},
 };
 
+/// Reads and decrypts the profile file at `file_path`, saving the profile
+/// writes it back to the same file through a [`SingleFileStore`].
 pub fn get_profile<P, F>(file_path: P, key_provider: Option<F>) -> Result<Profile>
 where
     P: AsRef<Path>,
     F: FnOnce(&ProfileMetadata) -> Result<Zeroizing<String>>,
 {
-    let file_path = file_path.as_ref().to_path_buf();
+    let file_path = file_path.as_ref();
+
+    let serialized_profile = utils::get_serialized_profile(file_path)?;
+    let store = SingleFileStore::new(file_path, &serialized_profile.metadata.name);
+
+    decrypt_profile(serialized_profile, Arc::new(store), key_provider)
+}
+
+/// Loads the profile called `name` from `store` and decrypts it.
+pub fn get_profile_from<F>(
+    store: Arc<dyn ProfileStore>,
+    name: &str,
+    key_provider: Option<F>,
+) -> Result<Profile>
+where
+    F: FnOnce(&ProfileMetadata) -> Result<Zeroizing<String>>,
+{
+    let mut serialized_profile = store.load(name)?;
+    serialized_profile.metadata.name = name.to_string();
+
+    decrypt_profile(serialized_profile, store, key_provider)
+}
+
+fn decrypt_profile<F>(
+    serialized_profile: SerializedProfile,
+    store: Arc<dyn ProfileStore>,
+    key_provider: Option<F>,
+) -> Result<Profile>
+where
+    F: FnOnce(&ProfileMetadata) -> Result<Zeroizing<String>>,
+{
+    if serialized_profile.metadata.version > PROFILE_VERSION {
+        return Err(Error::UnsupportedVersion {
+            kind: "profile".to_string(),
+            version: serialized_profile.metadata.version.to_string(),
+        });
+    }
 
-    let serialized_profile = utils::get_serialized_profile(&file_path)?;
     let mut cipher = cipher::create_cipher(serialized_profile.metadata.cipher_kind, None)?;
 
     if let Some(cipher_metadata) = &serialized_profile.metadata.cipher_metadata {
@@ -37,7 +84,10 @@ where
             Error::Msg("Key provider is required for profiles using encryption".into())
         })?;
 
-        let key = key_provider(&serialized_profile.metadata)?;
+        let key = key_provider(&serialized_profile.metadata).map_err(|e| match e {
+            Error::KeyProviderFailed(_) => e,
+            e => Error::KeyProviderFailed(Box::new(e)),
+        })?;
 
         match cipher.kind() {
             CipherKind::PASSPHRASE => cipher
@@ -56,26 +106,54 @@ where
 
     Ok(Profile {
         metadata: serialized_profile.metadata,
-        file_path,
+        store,
         envs: cipher.decrypt(&serialized_profile.content)?,
         cipher,
     })
 }
 
+/// The variables of a profile as a map, ready to pass to
+/// [`std::process::Command::envs`]. Expired variables are left out and the
+/// current process environment is not touched, see
+/// [`environment::build_environment`] to layer several profiles.
+pub fn get_environment<P, F>(
+    file_path: P,
+    key_provider: Option<F>,
+) -> Result<IndexMap<String, String>>
+where
+    P: AsRef<Path>,
+    F: FnOnce(&ProfileMetadata) -> Result<Zeroizing<String>>,
+{
+    let profile = get_profile(file_path, key_provider)?;
+
+    Ok(environment::build_environment(
+        std::iter::empty(),
+        [&profile.envs],
+        &Default::default(),
+    ))
+}
+
 // Use `get_profile` when you just need the decrypted `Profile` data (e.g. to inspect,
-// display, or modify it). Use `load_profile` when you want its variables actually
-// injected into the current process's environment.
+// display, or modify it). Use `command::CommandExt` or `get_environment` to hand the
+// variables to a child process. `load_profile` injects them into the environment of the
+// current process and is only built with the `load-profile` feature.
 //
 // ```ignore
 // // just need the data:
 // let profile = get_profile(path, key_provider)?;
 // println!("{}", profile.envs.len());
 //
-// // need it live in std::env (e.g. before spawning a child process):
-// let profile = load_profile(path, key_provider)?;
-// Command::new("npm").arg("run").arg("dev").status()?;
+// // need it in a child process:
+// Command::new("npm").arg("run").arg("dev").with_profile(&profile).status()?;
 // ```
-pub fn load_profile<P, F>(file_path: P, key_provider: Option<F>) -> Result<Profile>
+///
+/// # Safety
+///
+/// Calls [`std::env::set_var`], no other thread may read or write the
+/// environment while this runs. The variables stay visible to the whole
+/// process and every child it spawns.
+#[cfg(feature = "load-profile")]
+pub unsafe fn load_profile<P, F>(file_path: P, key_provider: Option<F>) -> Result<Profile>
 where
     P: AsRef<Path>,
     F: FnOnce(&ProfileMetadata) -> Result<Zeroizing<String>>,
@@ -92,3 +170,98 @@ where
 
     Ok(profile)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::{cipher::Cipher, store::FileStore};
+
+    type KeyProvider = fn(&ProfileMetadata) -> Result<Zeroizing<String>>;
+
+    fn temp_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("envio-test-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// saves an unencrypted profile called `prod` and moves it to `dir/file_name`
+    fn write_profile(dir: &Path, file_name: &str) -> PathBuf {
+        let mut envs = EnvMap::default();
+        envs.insert_from_key_value("API_URL", "https://example.com");
+
+        let store = Arc::new(FileStore::new(dir));
+        let mut profile = Profile::new(
+            "prod".to_string(),
+            None,
+            store.clone(),
+            envs,
+            cipher::create_cipher(CipherKind::NONE, None).unwrap(),
+        );
+        profile.save().unwrap();
+
+        let path = dir.join(file_name);
+        std::fs::rename(store.path("prod"), &path).unwrap();
+        path
+    }
+
+    fn save_and_reload(path: &Path) -> SerializedProfile {
+        let mut profile = get_profile(path, None::<KeyProvider>).unwrap();
+        profile.envs.insert_from_key_value("DEBUG", "false");
+        profile.save().unwrap();
+
+        serde_json::from_slice(&std::fs::read(path).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn get_profile_saves_to_the_loaded_file() {
+        let dir = temp_dir();
+        let path = write_profile(&dir, "prod.profile");
+
+        let saved = save_and_reload(&path);
+        let envs = cipher::NONE.decrypt(&saved.content).unwrap();
+
+        assert_eq!(saved.metadata.name, "prod");
+        assert_eq!(
+            envs.get("DEBUG").map(|env| env.value.as_str()),
+            Some("false")
+        );
+        assert!(!dir.join("prod.envio").exists());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn get_profile_keeps_the_name_of_renamed_files() {
+        let dir = temp_dir();
+        let path = write_profile(&dir, "renamed.envio");
+
+        let saved = save_and_reload(&path);
+
+        assert_eq!(saved.metadata.name, "prod");
+        assert!(!dir.join("prod.envio").exists());
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn saving_a_legacy_profile_bumps_its_version() {
+        let dir = temp_dir();
+        let path = write_profile(&dir, "prod.envio");
+
+        let legacy = std::fs::read_to_string(&path)
+            .unwrap()
+            .replace(r#""version": 2"#, r#""version": "0.8.0""#);
+        std::fs::write(&path, legacy).unwrap();
+
+        let profile = get_profile(&path, None::<KeyProvider>).unwrap();
+        assert_eq!(profile.metadata.version, 1);
+
+        let saved = save_and_reload(&path);
+        assert_eq!(saved.metadata.version, PROFILE_VERSION);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
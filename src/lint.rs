@@ -0,0 +1,361 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::Local;
+use serde::Serialize;
+use strum_macros::{AsRefStr, EnumIter, EnumString};
+
+use crate::{
+    env::EnvMap,
+    error::{Error, Result},
+    secrets::{self, Confidence},
+};
+
+/// values longer than this are reported by [`Rule::LargeValue`] unless
+/// [`LintOptions::max_value_size`] says otherwise
+pub const DEFAULT_MAX_VALUE_SIZE: usize = 4096;
+
+/// values shorter than this are too common (`true`, `8080`) to be worth
+/// reporting as duplicates
+const MIN_DUPLICATE_LEN: usize = 8;
+
+const PLACEHOLDERS: &[&str] = &[
+    "changeme",
+    "change_me",
+    "replaceme",
+    "replace_me",
+    "todo",
+    "fixme",
+    "placeholder",
+    "example",
+    "dummy",
+    "xxx",
+    "...",
+    "<required>",
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, EnumIter, EnumString, AsRefStr)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum Rule {
+    /// the key is not a POSIX name, `[A-Za-z_][A-Za-z0-9_]*`
+    InvalidName,
+    /// the key is not `UPPER_SNAKE_CASE`
+    NamingConvention,
+    EmptyValue,
+    /// leading or trailing whitespace in the value
+    Whitespace,
+    /// the value is still wrapped in quotes, or has a stray one at either end
+    QuoteLeftover,
+    /// the same value is stored under several keys
+    DuplicateValue,
+    /// the value looks like `changeme` or `<your-token>`
+    Placeholder,
+    LargeValue,
+    /// a value that looks like a secret in a profile without encryption, see
+    /// [`lint_unencrypted`]
+    UnencryptedSecret,
+}
+
+impl Rule {
+    pub fn description(&self) -> &'static str {
+        match self {
+            Rule::InvalidName => "key is not a valid POSIX variable name",
+            Rule::NamingConvention => "key is not UPPER_SNAKE_CASE",
+            Rule::EmptyValue => "value is empty",
+            Rule::Whitespace => "value has leading or trailing whitespace",
+            Rule::QuoteLeftover => "value is wrapped in quotes or has a stray one",
+            Rule::DuplicateValue => "the same value is stored under several keys",
+            Rule::Placeholder => "value looks like a placeholder",
+            Rule::LargeValue => "value is very large",
+            Rule::UnencryptedSecret => "value looks like a secret but the profile is not encrypted",
+        }
+    }
+}
+
+impl std::fmt::Display for Rule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// a change that resolves an [`Issue`] without a human deciding anything
+#[derive(Clone, PartialEq, Debug, Serialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum Fix {
+    /// serialized with the new key as `value`
+    RenameKey(String),
+    /// the new value is left out of the serialized form, it may be a secret
+    SetValue(#[serde(skip)] String),
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Issue {
+    pub key: String,
+    pub rule: Rule,
+    pub severity: Severity,
+    pub message: String,
+    pub fix: Option<Fix>,
+}
+
+#[derive(Clone)]
+pub struct LintOptions {
+    pub disabled: HashSet<Rule>,
+    pub max_value_size: usize,
+}
+
+impl Default for LintOptions {
+    fn default() -> Self {
+        Self {
+            disabled: HashSet::new(),
+            max_value_size: DEFAULT_MAX_VALUE_SIZE,
+        }
+    }
+}
+
+impl LintOptions {
+    fn enabled(&self, rule: Rule) -> bool {
+        !self.disabled.contains(&rule)
+    }
+}
+
+pub fn is_posix_name(key: &str) -> bool {
+    let mut chars = key.chars();
+
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// checks every variable of `envs`, issues are in the order of the variables
+pub fn lint(envs: &EnvMap, options: &LintOptions) -> Vec<Issue> {
+    let mut issues = Vec::new();
+
+    let mut seen_values: HashMap<&str, &str> = HashMap::new();
+
+    for env in envs {
+        let mut issue = |rule: Rule, severity: Severity, message: String, fix: Option<Fix>| {
+            if options.enabled(rule) {
+                issues.push(Issue {
+                    key: env.key.clone(),
+                    rule,
+                    severity,
+                    message,
+                    fix,
+                });
+            }
+        };
+
+        let key = env.key.as_str();
+        let value = env.value.as_str();
+
+        if !is_posix_name(key) {
+            let fixed = conventional_name(key);
+            issue(
+                Rule::InvalidName,
+                Severity::Error,
+                "not a valid variable name, use letters, digits and `_` and don't start with a digit"
+                    .to_string(),
+                is_posix_name(&fixed)
+                    .then_some(Fix::RenameKey(fixed))
+                    .filter(|fix| !renames_onto_existing(envs, key, fix)),
+            );
+        } else if key != key.to_ascii_uppercase() {
+            let fixed = key.to_ascii_uppercase();
+            issue(
+                Rule::NamingConvention,
+                Severity::Warning,
+                format!("should be UPPER_SNAKE_CASE, `{}`", fixed),
+                Some(Fix::RenameKey(fixed)).filter(|fix| !renames_onto_existing(envs, key, fix)),
+            );
+        }
+
+        if value.is_empty() {
+            issue(
+                Rule::EmptyValue,
+                Severity::Warning,
+                "the value is empty".to_string(),
+                None,
+            );
+            continue;
+        }
+
+        if value.trim() != value {
+            issue(
+                Rule::Whitespace,
+                Severity::Warning,
+                "the value has leading or trailing whitespace".to_string(),
+                Some(Fix::SetValue(value.trim().to_string())),
+            );
+        }
+
+        let trimmed = value.trim();
+        if let Some(unquoted) = strip_quotes(trimmed) {
+            issue(
+                Rule::QuoteLeftover,
+                Severity::Warning,
+                "the value is wrapped in quotes, they will be part of the variable".to_string(),
+                Some(Fix::SetValue(unquoted.to_string())),
+            );
+        } else if trimmed.starts_with(['"', '\'']) || trimmed.ends_with(['"', '\'']) {
+            issue(
+                Rule::QuoteLeftover,
+                Severity::Warning,
+                "the value starts or ends with a stray quote".to_string(),
+                None,
+            );
+        }
+
+        if is_placeholder(trimmed) {
+            issue(
+                Rule::Placeholder,
+                Severity::Warning,
+                if env.is_secret() {
+                    "the value looks like a placeholder".to_string()
+                } else {
+                    format!("`{}` looks like a placeholder", trimmed)
+                },
+                None,
+            );
+        }
+
+        if value.len() > options.max_value_size {
+            issue(
+                Rule::LargeValue,
+                Severity::Warning,
+                format!(
+                    "the value is {} bytes, more than {}",
+                    value.len(),
+                    options.max_value_size
+                ),
+                None,
+            );
+        }
+
+        if value.len() >= MIN_DUPLICATE_LEN {
+            if let Some(first) = seen_values.get(value) {
+                issue(
+                    Rule::DuplicateValue,
+                    Severity::Warning,
+                    format!("same value as `{}`", first),
+                    None,
+                );
+            } else {
+                seen_values.insert(value, key);
+            }
+        }
+    }
+
+    issues
+}
+
+/// reports the values of an unencrypted profile that are secrets with high
+/// confidence, [`lint`] can't tell whether a profile is encrypted
+pub fn lint_unencrypted(envs: &EnvMap, options: &LintOptions) -> Vec<Issue> {
+    if !options.enabled(Rule::UnencryptedSecret) {
+        return Vec::new();
+    }
+
+    secrets::detect(envs)
+        .into_iter()
+        .filter(|detection| detection.confidence == Confidence::High)
+        .map(|detection| Issue {
+            key: detection.key,
+            rule: Rule::UnencryptedSecret,
+            severity: Severity::Warning,
+            message: format!(
+                "looks like a secret ({}) but the profile is not encrypted",
+                detection.kind
+            ),
+            fix: None,
+        })
+        .collect()
+}
+
+/// applies the fixes of `issues` and returns how many were applied
+///
+/// Value fixes are applied in order, so a value that is both padded and
+/// quoted ends up trimmed and unquoted.
+pub fn apply_fixes(envs: &mut EnvMap, issues: &[Issue]) -> Result<usize> {
+    let mut applied = 0;
+    let mut renames = Vec::new();
+
+    for issue in issues {
+        match &issue.fix {
+            Some(Fix::SetValue(_)) => {
+                let Some(env) = envs.iter_mut().find(|env| env.key == issue.key) else {
+                    return Err(Error::EnvDoesNotExist(issue.key.clone()));
+                };
+
+                // recompute from the current value so successive fixes stack
+                let fixed = match issue.rule {
+                    Rule::Whitespace => env.value.trim().to_string(),
+                    Rule::QuoteLeftover => strip_quotes(env.value.trim())
+                        .unwrap_or(&env.value)
+                        .to_string(),
+                    _ => continue,
+                };
+
+                env.value = fixed;
+                env.updated_at = Some(Local::now());
+                applied += 1;
+            }
+            Some(Fix::RenameKey(new_key)) => renames.push((issue.key.clone(), new_key.clone())),
+            None => {}
+        }
+    }
+
+    // renames last so the value fixes above still find their keys
+    for (key, new_key) in renames {
+        if envs.contains_key(&new_key) {
+            continue;
+        }
+
+        envs.rename(&key, new_key)?;
+        applied += 1;
+    }
+
+    Ok(applied)
+}
+
+/// `my-api.key` -> `MY_API_KEY`
+fn conventional_name(key: &str) -> String {
+    key.trim()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn renames_onto_existing(envs: &EnvMap, key: &str, fix: &Fix) -> bool {
+    matches!(fix, Fix::RenameKey(new_key) if new_key != key && envs.contains_key(new_key))
+}
+
+fn strip_quotes(value: &str) -> Option<&str> {
+    ['"', '\''].into_iter().find_map(|quote| {
+        value
+            .strip_prefix(quote)
+            .and_then(|rest| rest.strip_suffix(quote))
+            .filter(|inner| !inner.contains(quote))
+    })
+}
+
+fn is_placeholder(value: &str) -> bool {
+    let lower = value.to_ascii_lowercase();
+
+    PLACEHOLDERS.contains(&lower.as_str())
+        || (lower.starts_with('<') && lower.ends_with('>'))
+        || lower.starts_with("your_")
+        || lower.starts_with("your-")
+        || (lower.len() >= 3 && lower.chars().all(|c| c == 'x'))
+}
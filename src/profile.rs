@@ -1,21 +1,32 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, sync::Arc};
 
 use chrono::{DateTime, Local};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, de::DeserializeOwned};
 use uuid::Uuid;
 
 use crate::{
     cipher::{Cipher, CipherKind, EncryptedContent},
     env::{Env, EnvMap},
     error::Result,
-    utils::save_serialized_profile,
+    store::ProfileStore,
 };
 
+/// the format of the profiles this version of envio writes, bumped when older
+/// versions can't read them. It is stored as a number, envio 0.8 and older
+/// expect a string there so they fail to parse newer profiles instead of
+/// misreading their variables.
+///
+/// 1. variables encoded with postcard, the version field held the envio version
+/// 2. variables encoded as JSON, see [`EnvMap::as_bytes`]
+pub const PROFILE_VERSION: u32 = 2;
+
 #[derive(Clone, Serialize, Deserialize)]
 pub struct ProfileMetadata {
     pub uuid: String,
     pub name: String,
-    pub version: String,
+    /// see [`PROFILE_VERSION`]
+    #[serde(deserialize_with = "deserialize_version")]
+    pub version: u32,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     pub cipher_kind: CipherKind,
@@ -28,12 +39,13 @@ pub struct ProfileMetadata {
 #[derive(Clone)]
 pub struct Profile {
     pub metadata: ProfileMetadata,
-    pub file_path: PathBuf,
+    /// where [`Profile::save`] writes to, under [`ProfileMetadata::name`]
+    pub store: Arc<dyn ProfileStore>,
     pub envs: EnvMap,
     pub cipher: Box<dyn Cipher>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct SerializedProfile {
     pub metadata: ProfileMetadata,
     pub content: EncryptedContent,
@@ -43,7 +55,7 @@ impl Profile {
     pub fn new(
         name: String,
         description: Option<String>,
-        file_path: impl Into<PathBuf>,
+        store: Arc<dyn ProfileStore>,
         envs: EnvMap,
         cipher: Box<dyn Cipher>,
     ) -> Self {
@@ -51,27 +63,45 @@ impl Profile {
             metadata: ProfileMetadata {
                 uuid: Uuid::new_v4().to_string(),
                 name,
-                version: env!("CARGO_PKG_VERSION").to_string(),
+                version: PROFILE_VERSION,
                 description,
                 cipher_kind: cipher.kind(),
                 cipher_metadata: cipher.export_metadata(),
                 created_at: Local::now(),
                 updated_at: Local::now(),
             },
-            file_path: file_path.into(),
+            store,
             envs,
             cipher,
         }
     }
 
+    /// the file the profile is saved to, `None` unless it lives in a [`FileStore`]
+    ///
+    /// [`FileStore`]: crate::store::FileStore
+    #[deprecated(
+        since = "0.9.0",
+        note = "profiles are saved to `Profile::store`, use `ProfileStore::file_path` with the profile name"
+    )]
+    pub fn file_path(&self) -> Option<PathBuf> {
+        self.store.file_path(&self.metadata.name)
+    }
+
     pub fn expired_envs(&self) -> Vec<&Env> {
         self.envs.iter().filter(|env| env.is_expired()).collect()
     }
 
+    /// the profile's variables as a config struct, see [`EnvMap::deserialize`]
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T> {
+        self.envs.deserialize()
+    }
+
     pub fn save(&mut self) -> Result<()> {
         let encrypted_envs = self.cipher.encrypt(&self.envs)?;
 
+        self.metadata.version = PROFILE_VERSION;
         self.metadata.updated_at = Local::now();
+        self.metadata.cipher_kind = self.cipher.kind();
         self.metadata.cipher_metadata = self.cipher.export_metadata();
 
         let serialized_profile = SerializedProfile {
@@ -79,8 +109,26 @@ impl Profile {
             content: encrypted_envs,
         };
 
-        save_serialized_profile(&self.file_path, serialized_profile)?;
+        self.store.save(&self.metadata.name, &serialized_profile)?;
 
         Ok(())
     }
 }
+
+/// profiles written before [`PROFILE_VERSION`] was a number are version 1
+fn deserialize_version<'de, D>(deserializer: D) -> std::result::Result<u32, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Version {
+        Number(u32),
+        EnvioVersion(#[allow(dead_code)] String),
+    }
+
+    Ok(match Version::deserialize(deserializer)? {
+        Version::Number(version) => version,
+        Version::EnvioVersion(_) => 1,
+    })
+}
@@ -0,0 +1,306 @@
+//! Where profiles are kept. [`crate::Profile::save`] and [`crate::get_profile_from`]
+//! go through a [`ProfileStore`], so the same profiles can live in files, in
+//! memory or, with the `sqlite` feature, in a database.
+
+#[cfg(feature = "sqlite")]
+mod sqlite;
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use indexmap::IndexMap;
+
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteStore;
+
+use crate::{
+    error::{Error, Result},
+    profile::{ProfileMetadata, SerializedProfile},
+    utils,
+};
+
+/// Profiles are addressed by name, which is also [`ProfileMetadata::name`].
+///
+/// Stores only see encrypted profiles, decrypting is up to the caller.
+pub trait ProfileStore: Send + Sync {
+    /// names of every profile in the store
+    fn list(&self) -> Result<Vec<String>>;
+
+    /// the metadata of every profile, with [`ProfileMetadata::name`] set to the
+    /// name in the store, stores that index it should override this
+    fn list_metadata(&self) -> Result<Vec<ProfileMetadata>> {
+        self.list()?
+            .into_iter()
+            .map(|name| {
+                let mut metadata = self.load_metadata(&name)?;
+                metadata.name = name;
+                Ok(metadata)
+            })
+            .collect()
+    }
+
+    fn exists(&self, name: &str) -> Result<bool> {
+        Ok(self.list()?.iter().any(|n| n == name))
+    }
+
+    /// errors with [`Error::ProfileDoesNotExist`] if there is no profile called `name`
+    fn load(&self, name: &str) -> Result<SerializedProfile>;
+
+    /// the metadata without the encrypted content, stores that keep the two
+    /// apart should override this
+    fn load_metadata(&self, name: &str) -> Result<ProfileMetadata> {
+        Ok(self.load(name)?.metadata)
+    }
+
+    /// creates the profile or replaces it
+    fn save(&self, name: &str, profile: &SerializedProfile) -> Result<()>;
+
+    fn delete(&self, name: &str) -> Result<()>;
+
+    /// renames the profile and its [`ProfileMetadata::name`], errors with
+    /// [`Error::ProfileExists`] if `new_name` is taken
+    fn rename(&self, name: &str, new_name: &str) -> Result<()>;
+
+    /// the file holding the profile, `None` for stores without one file per profile
+    fn file_path(&self, _name: &str) -> Option<PathBuf> {
+        None
+    }
+}
+
+/// one `<name>.envio` JSON file per profile in a directory
+#[derive(Clone)]
+pub struct FileStore {
+    dir: PathBuf,
+}
+
+impl FileStore {
+    pub const EXTENSION: &str = "envio";
+
+    /// the directory is created when the first profile is saved
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path(&self, name: &str) -> PathBuf {
+        self.dir.join(format!("{}.{}", name, Self::EXTENSION))
+    }
+
+    fn existing_path(&self, name: &str) -> Result<PathBuf> {
+        let path = self.path(name);
+
+        if !path.exists() {
+            return Err(Error::ProfileDoesNotExist(name.to_string()));
+        }
+
+        Ok(path)
+    }
+}
+
+impl ProfileStore for FileStore {
+    fn list(&self) -> Result<Vec<String>> {
+        let mut names = Vec::new();
+
+        if !self.dir.exists() {
+            return Ok(names);
+        }
+
+        for entry in std::fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+
+            if path.extension().and_then(|s| s.to_str()) != Some(Self::EXTENSION) {
+                continue;
+            }
+
+            if let Some(name) = path.file_stem().and_then(|s| s.to_str())
+                && !name.starts_with('.')
+            {
+                names.push(name.to_owned());
+            }
+        }
+
+        Ok(names)
+    }
+
+    fn exists(&self, name: &str) -> Result<bool> {
+        Ok(self.path(name).exists())
+    }
+
+    fn load(&self, name: &str) -> Result<SerializedProfile> {
+        utils::get_serialized_profile(self.existing_path(name)?)
+    }
+
+    fn save(&self, name: &str, profile: &SerializedProfile) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        utils::save_serialized_profile(self.path(name), profile)
+    }
+
+    fn delete(&self, name: &str) -> Result<()> {
+        Ok(std::fs::remove_file(self.existing_path(name)?)?)
+    }
+
+    fn rename(&self, name: &str, new_name: &str) -> Result<()> {
+        let path = self.existing_path(name)?;
+        let new_path = self.path(new_name);
+
+        if new_name != name && new_path.exists() {
+            return Err(Error::ProfileExists(new_name.to_string()));
+        }
+
+        let mut profile = utils::get_serialized_profile(&path)?;
+        profile.metadata.name = new_name.to_string();
+        utils::save_serialized_profile(&path, &profile)?;
+
+        Ok(std::fs::rename(path, new_path)?)
+    }
+
+    fn file_path(&self, name: &str) -> Option<PathBuf> {
+        Some(self.path(name))
+    }
+}
+
+/// a single profile file at a fixed path, whatever its name or extension, the
+/// store [`crate::get_profile`] saves back to
+pub struct SingleFileStore {
+    path: PathBuf,
+    name: String,
+}
+
+impl SingleFileStore {
+    /// `name` is the [`ProfileMetadata::name`] of the profile in the file
+    pub fn new(path: impl Into<PathBuf>, name: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            name: name.into(),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn check_name(&self, name: &str) -> Result<()> {
+        if name != self.name {
+            return Err(Error::ProfileDoesNotExist(name.to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+impl ProfileStore for SingleFileStore {
+    fn list(&self) -> Result<Vec<String>> {
+        Ok(if self.path.exists() {
+            vec![self.name.clone()]
+        } else {
+            Vec::new()
+        })
+    }
+
+    fn exists(&self, name: &str) -> Result<bool> {
+        Ok(name == self.name && self.path.exists())
+    }
+
+    fn load(&self, name: &str) -> Result<SerializedProfile> {
+        self.check_name(name)?;
+        utils::get_serialized_profile(&self.path)
+    }
+
+    fn save(&self, name: &str, profile: &SerializedProfile) -> Result<()> {
+        if name != self.name {
+            return Err(Error::Store(format!(
+                "{} only holds the profile `{}`",
+                self.path.display(),
+                self.name
+            )));
+        }
+
+        utils::save_serialized_profile(&self.path, profile)
+    }
+
+    fn delete(&self, name: &str) -> Result<()> {
+        self.check_name(name)?;
+        Ok(std::fs::remove_file(&self.path)?)
+    }
+
+    fn rename(&self, name: &str, _new_name: &str) -> Result<()> {
+        self.check_name(name)?;
+        Err(Error::Store(format!(
+            "the profile in {} can't be renamed",
+            self.path.display()
+        )))
+    }
+
+    fn file_path(&self, name: &str) -> Option<PathBuf> {
+        (name == self.name).then(|| self.path.clone())
+    }
+}
+
+/// keeps profiles for the lifetime of the store, for tests and embedding
+#[derive(Default)]
+pub struct MemoryStore {
+    profiles: Mutex<IndexMap<String, SerializedProfile>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn profiles(&self) -> std::sync::MutexGuard<'_, IndexMap<String, SerializedProfile>> {
+        // a panic while holding the lock can't leave a profile half written
+        self.profiles
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl ProfileStore for MemoryStore {
+    fn list(&self) -> Result<Vec<String>> {
+        Ok(self.profiles().keys().cloned().collect())
+    }
+
+    fn exists(&self, name: &str) -> Result<bool> {
+        Ok(self.profiles().contains_key(name))
+    }
+
+    fn load(&self, name: &str) -> Result<SerializedProfile> {
+        self.profiles()
+            .get(name)
+            .cloned()
+            .ok_or_else(|| Error::ProfileDoesNotExist(name.to_string()))
+    }
+
+    fn save(&self, name: &str, profile: &SerializedProfile) -> Result<()> {
+        self.profiles().insert(name.to_string(), profile.clone());
+        Ok(())
+    }
+
+    fn delete(&self, name: &str) -> Result<()> {
+        self.profiles()
+            .shift_remove(name)
+            .map(|_| ())
+            .ok_or_else(|| Error::ProfileDoesNotExist(name.to_string()))
+    }
+
+    fn rename(&self, name: &str, new_name: &str) -> Result<()> {
+        let mut profiles = self.profiles();
+
+        if new_name != name && profiles.contains_key(new_name) {
+            return Err(Error::ProfileExists(new_name.to_string()));
+        }
+
+        let Some((index, _, mut profile)) = profiles.shift_remove_full(name) else {
+            return Err(Error::ProfileDoesNotExist(name.to_string()));
+        };
+
+        profile.metadata.name = new_name.to_string();
+        profiles.shift_insert(index, new_name.to_string(), profile);
+        Ok(())
+    }
+}
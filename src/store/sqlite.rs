@@ -0,0 +1,315 @@
+use std::{path::Path, sync::Mutex};
+
+use chrono::{DateTime, Local};
+use rusqlite::{Connection, OptionalExtension, Row, params};
+
+use crate::{
+    cipher::{CipherKind, EncryptedContent},
+    error::{Error, Result},
+    profile::{ProfileMetadata, SerializedProfile},
+};
+
+use super::ProfileStore;
+
+/// bumped when the tables change, checked against `PRAGMA user_version`
+const SCHEMA_VERSION: i64 = 1;
+
+const METADATA_COLUMNS: &str =
+    "name, uuid, version, description, cipher_kind, cipher_metadata, created_at, updated_at";
+
+/// Every profile in one SQLite database.
+///
+/// The metadata is kept in indexed columns so listing profiles doesn't read the
+/// encrypted content, which is stored as a blob next to it.
+pub struct SqliteStore {
+    conn: Mutex<Connection>,
+}
+
+impl SqliteStore {
+    /// opens the database at `path`, creating it and its tables if needed
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        Self::init(Connection::open(path).map_err(sqlite_error)?)
+    }
+
+    pub fn open_in_memory() -> Result<Self> {
+        Self::init(Connection::open_in_memory().map_err(sqlite_error)?)
+    }
+
+    fn init(conn: Connection) -> Result<Self> {
+        let version: i64 = conn
+            .query_row("PRAGMA user_version", [], |row| row.get(0))
+            .map_err(sqlite_error)?;
+
+        if version > SCHEMA_VERSION {
+            return Err(Error::UnsupportedVersion {
+                kind: "profile database".to_string(),
+                version: version.to_string(),
+            });
+        }
+
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS profiles (
+                name TEXT PRIMARY KEY NOT NULL,
+                uuid TEXT NOT NULL,
+                version TEXT NOT NULL,
+                description TEXT,
+                cipher_kind TEXT NOT NULL,
+                cipher_metadata TEXT,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL,
+                content_format TEXT NOT NULL,
+                content BLOB NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS profiles_uuid ON profiles (uuid);
+            CREATE INDEX IF NOT EXISTS profiles_updated_at ON profiles (updated_at);
+            CREATE INDEX IF NOT EXISTS profiles_cipher_kind ON profiles (cipher_kind);
+            PRAGMA user_version = 1;",
+        )
+        .map_err(sqlite_error)?;
+
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    fn conn(&self) -> std::sync::MutexGuard<'_, Connection> {
+        self.conn
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl ProfileStore for SqliteStore {
+    fn list(&self) -> Result<Vec<String>> {
+        let conn = self.conn();
+        let mut stmt = conn
+            .prepare("SELECT name FROM profiles ORDER BY name")
+            .map_err(sqlite_error)?;
+
+        stmt.query_map([], |row| row.get(0))
+            .map_err(sqlite_error)?
+            .collect::<rusqlite::Result<_>>()
+            .map_err(sqlite_error)
+    }
+
+    fn list_metadata(&self) -> Result<Vec<ProfileMetadata>> {
+        let conn = self.conn();
+        let mut stmt = conn
+            .prepare(&format!(
+                "SELECT {} FROM profiles ORDER BY name",
+                METADATA_COLUMNS
+            ))
+            .map_err(sqlite_error)?;
+
+        let rows = stmt
+            .query_map([], MetadataRow::read)
+            .map_err(sqlite_error)?;
+
+        rows.map(|row| row.map_err(sqlite_error)?.parse()).collect()
+    }
+
+    fn exists(&self, name: &str) -> Result<bool> {
+        self.conn()
+            .query_row(
+                "SELECT EXISTS (SELECT 1 FROM profiles WHERE name = ?1)",
+                [name],
+                |row| row.get(0),
+            )
+            .map_err(sqlite_error)
+    }
+
+    fn load(&self, name: &str) -> Result<SerializedProfile> {
+        let row = self
+            .conn()
+            .query_row(
+                &format!(
+                    "SELECT {}, content_format, content FROM profiles WHERE name = ?1",
+                    METADATA_COLUMNS
+                ),
+                [name],
+                |row| {
+                    Ok((
+                        MetadataRow::read(row)?,
+                        row.get::<_, String>(8)?,
+                        row.get::<_, Vec<u8>>(9)?,
+                    ))
+                },
+            )
+            .optional()
+            .map_err(sqlite_error)?;
+
+        let Some((metadata, format, content)) = row else {
+            return Err(Error::ProfileDoesNotExist(name.to_string()));
+        };
+
+        let content = match format.as_str() {
+            "bytes" => EncryptedContent::Bytes(content),
+            "json" => EncryptedContent::Json(
+                serde_json::from_slice(&content).map_err(|e| Error::Corrupted(e.to_string()))?,
+            ),
+            _ => {
+                return Err(Error::Corrupted(format!(
+                    "unknown content format `{}`",
+                    format
+                )));
+            }
+        };
+
+        Ok(SerializedProfile {
+            metadata: metadata.parse()?,
+            content,
+        })
+    }
+
+    fn load_metadata(&self, name: &str) -> Result<ProfileMetadata> {
+        self.conn()
+            .query_row(
+                &format!("SELECT {} FROM profiles WHERE name = ?1", METADATA_COLUMNS),
+                [name],
+                MetadataRow::read,
+            )
+            .optional()
+            .map_err(sqlite_error)?
+            .ok_or_else(|| Error::ProfileDoesNotExist(name.to_string()))?
+            .parse()
+    }
+
+    fn save(&self, name: &str, profile: &SerializedProfile) -> Result<()> {
+        let metadata = &profile.metadata;
+
+        let cipher_metadata = metadata
+            .cipher_metadata
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()?;
+
+        let (format, content) = match &profile.content {
+            EncryptedContent::Bytes(bytes) => ("bytes", bytes.clone()),
+            EncryptedContent::Json(value) => ("json", serde_json::to_vec(value)?),
+        };
+
+        self.conn()
+            .execute(
+                "INSERT OR REPLACE INTO profiles (
+                    name, uuid, version, description, cipher_kind, cipher_metadata,
+                    created_at, updated_at, content_format, content
+                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                params![
+                    name,
+                    metadata.uuid,
+                    metadata.version.to_string(),
+                    metadata.description,
+                    metadata.cipher_kind.as_ref(),
+                    cipher_metadata,
+                    metadata.created_at.to_rfc3339(),
+                    metadata.updated_at.to_rfc3339(),
+                    format,
+                    content,
+                ],
+            )
+            .map_err(sqlite_error)?;
+
+        Ok(())
+    }
+
+    fn delete(&self, name: &str) -> Result<()> {
+        let deleted = self
+            .conn()
+            .execute("DELETE FROM profiles WHERE name = ?1", [name])
+            .map_err(sqlite_error)?;
+
+        if deleted == 0 {
+            return Err(Error::ProfileDoesNotExist(name.to_string()));
+        }
+
+        Ok(())
+    }
+
+    fn rename(&self, name: &str, new_name: &str) -> Result<()> {
+        if new_name != name && self.exists(new_name)? {
+            return Err(Error::ProfileExists(new_name.to_string()));
+        }
+
+        let renamed = self
+            .conn()
+            .execute(
+                "UPDATE profiles SET name = ?2 WHERE name = ?1",
+                [name, new_name],
+            )
+            .map_err(sqlite_error)?;
+
+        if renamed == 0 {
+            return Err(Error::ProfileDoesNotExist(name.to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+/// the columns of [`METADATA_COLUMNS`] before they are parsed
+struct MetadataRow {
+    name: String,
+    uuid: String,
+    version: String,
+    description: Option<String>,
+    cipher_kind: String,
+    cipher_metadata: Option<String>,
+    created_at: String,
+    updated_at: String,
+}
+
+impl MetadataRow {
+    fn read(row: &Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            name: row.get(0)?,
+            uuid: row.get(1)?,
+            version: row.get(2)?,
+            description: row.get(3)?,
+            cipher_kind: row.get(4)?,
+            cipher_metadata: row.get(5)?,
+            created_at: row.get(6)?,
+            updated_at: row.get(7)?,
+        })
+    }
+
+    fn parse(self) -> Result<ProfileMetadata> {
+        Ok(ProfileMetadata {
+            name: self.name,
+            uuid: self.uuid,
+            version: self
+                .version
+                .parse()
+                .map_err(|_| Error::Corrupted(format!("invalid version `{}`", self.version)))?,
+            description: self.description,
+            cipher_kind: self
+                .cipher_kind
+                .parse::<CipherKind>()
+                .map_err(|_| Error::Corrupted(format!("unknown cipher `{}`", self.cipher_kind)))?,
+            cipher_metadata: self
+                .cipher_metadata
+                .map(|json| serde_json::from_str(&json))
+                .transpose()
+                .map_err(|e| Error::Corrupted(e.to_string()))?,
+            created_at: parse_timestamp(&self.created_at)?,
+            updated_at: parse_timestamp(&self.updated_at)?,
+        })
+    }
+}
+
+fn parse_timestamp(value: &str) -> Result<DateTime<Local>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|date| date.with_timezone(&Local))
+        .map_err(|e| Error::Corrupted(format!("invalid timestamp `{}`: {}", value, e)))
+}
+
+fn sqlite_error(e: rusqlite::Error) -> Error {
+    Error::Store(format!("sqlite: {}", e))
+}
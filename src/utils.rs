@@ -1,16 +1,19 @@
 use std::path::Path;
 
-use crate::{error::Result, profile::SerializedProfile};
+use crate::{
+    error::{Error, Result},
+    profile::SerializedProfile,
+};
 
 pub fn get_serialized_profile<P: AsRef<Path>>(file_path: P) -> Result<SerializedProfile> {
     let file_content = std::fs::read(&file_path)?;
 
-    Ok(serde_json::from_slice(&file_content)?)
+    serde_json::from_slice(&file_content).map_err(|e| Error::Corrupted(e.to_string()))
 }
 
 pub fn save_serialized_profile<P: AsRef<Path>>(
     file_path: P,
-    serialized_profile: SerializedProfile,
+    serialized_profile: &SerializedProfile,
 ) -> Result<()> {
     let file = std::fs::OpenOptions::new()
         .write(true)
@@ -19,7 +22,7 @@ pub fn save_serialized_profile<P: AsRef<Path>>(
         .create(true)
         .open(&file_path)?;
 
-    serde_json::to_writer_pretty(&file, &serialized_profile)?;
+    serde_json::to_writer_pretty(&file, serialized_profile)?;
 
     Ok(())
 }